@@ -0,0 +1,7336 @@
+/// **Bit-preserving conversions.**
+///
+/// Transmutation is the act of reinterpreting the bytes corresponding to a value of one type as if they corresponded to a different type. A transmutation of a `Src` to a `Dst` type is similar to defining a union with two variants:
+/// ```rust
+/// #![feature(untagged_unions)]
+/// union Transmute<Src, Dst> {
+///     src: ManuallyDrop<Src>,
+///     dst: ManuallyDrop<Dst>,
+/// }
+/// ```
+/// And instantiating that union with its `src` variant, then reading `dst` back out. The [TransmuteFrom] and [TransmuteInto] traits are implemented *automatically* for all `Src` and `Dst` types where it is *sound*, *safe*, and *stable* to do this conversion:
+///  - A transmutation is ***sound*** if the mere act of transmuting a value from one type to another is not compiler undefined behavior.
+///  - A sound transmutation is ***safe*** if *using* the transmuted value cannot violate memory safety.
+///  - A safe transmutation is ***stable*** if the authors of the source type and destination types [have indicated that the layouts of those types is part of their libraries' stability guarantees][stability].
+///
+/// For more information on these concepts [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#concepts-in-depth).
+///
+/// ## Example
+/// Given:
+/// ```rust
+/// use core::convert::transmute::{
+///     TransmuteInto,
+///     stability::{PromiseTransmutableInto, PromiseTransmutableFrom},
+/// };
+///
+/// #[derive(PromiseTransmutableInto, PromiseTransmutableFrom)]
+/// #[repr(C)]
+/// pub struct Foo(pub u8, pub u16);
+/// //                    ^ there's a padding byte here, between these fields
+/// ```
+/// This transmutation accepted:
+/// ```rust
+/// let _ : Foo = 64u32.transmute_into(); // Alchemy Achieved!
+/// //                  ^^^^^^^^^^^^^^ provided by the `TransmuteInto` trait
+/// ```
+/// But this transmutation is rejected:
+/// ```compile_fail
+/// let _ : u32 = Foo(16, 12).transmute_into();
+/// // error[E0277]: the trait bound `u32: TransmuteFrom<Foo, _>` is not satisfied
+/// //   --> src/demo.rs:15:27
+/// //    |
+/// // 15 | let _ : u32 = Foo(16, 12).transmute_into();
+/// //    |                           ^^^^^^^^^^^^^^ the trait `TransmuteFrom<foo::Foo, _>` is not implemented for `u32`
+/// //    |
+/// //   = note: required because of the requirements on the impl of `TransmuteInto<u32, _>` for `foo::Foo`
+/// ```
+pub mod transmute {
+    use {options::*, stability::*};
+
+    use core::mem::ManuallyDrop;
+
+    /// The union described in the [module documentation][self], used to transmute in `const` contexts, where trait methods cannot be called.
+    #[repr(C)]
+    union Transmute<Src, Dst> {
+        src: ManuallyDrop<Src>,
+        dst: ManuallyDrop<Dst>,
+    }
+
+    /// Reinterprets the bits of a value of one type as another type, safely.
+    ///
+    /// Unlike [TransmuteInto::transmute_into], this function may be called in `const` contexts; e.g., to generate static tables:
+    /// ```rust
+    /// const HEADER: [u8; 8] = safe_transmute(MY_HEADER);
+    /// ```
+    #[inline(always)]
+    pub const fn safe_transmute<Src, Dst, Neglect>(src: Src) -> Dst
+    where
+        Src: TransmuteInto<Dst, Neglect>,
+        Neglect: SafeTransmuteOptions
+    {
+        // Safe because the bounds above guarantee that this transmutation is safe.
+        unsafe { ManuallyDrop::into_inner(Transmute { src: ManuallyDrop::new(src) }.dst) }
+    }
+
+    /// Reinterprets the bits of a value of one type as another type, potentially unsafely.
+    ///
+    /// Like [safe_transmute], this function may be called in `const` contexts.
+    #[inline(always)]
+    pub const unsafe fn unsafe_transmute<Src, Dst, Neglect>(src: Src) -> Dst
+    where
+        Src: TransmuteInto<Dst, Neglect>,
+        Neglect: UnsafeTransmuteOptions
+    {
+        ManuallyDrop::into_inner(Transmute { src: ManuallyDrop::new(src) }.dst)
+    }
+
+    /// Reads the bits of a referenced value of one type as a value of another type, safely.
+    ///
+    /// This is the safe counterpart of [mem::transmute_copy](core::mem::transmute_copy), for when only a reference to the source value is at hand. Unlike `transmute_copy`, which is wholly unchecked, the transmutation of `Src` into `Dst` must be valid per [TransmuteInto]; in particular, `Dst` may be *smaller* than `Src` (in which case only the leading bytes of `Src` are read), but never larger:
+    /// ```rust
+    /// let packet: [u8; 64] = receive();
+    /// let tag: u32 = safe_transmute_copy(&packet);
+    /// ```
+    ///
+    /// Since the source value is copied, `Src` must be [Copy]; otherwise, values that uniquely own resources (e.g., a `Box`) could be duplicated.
+    #[inline(always)]
+    pub const fn safe_transmute_copy<Src, Dst, Neglect>(src: &Src) -> Dst
+    where
+        Src: Copy + TransmuteInto<Dst, Neglect>,
+        Neglect: SafeTransmuteOptions
+    {
+        safe_transmute::<Src, Dst, Neglect>(*src)
+    }
+
+    /// Reinterprets a reference to one type as a reference to another type, safely.
+    ///
+    /// This is shorthand for [safe_transmute] on references; e.g.:
+    /// ```rust
+    /// let header: &Header = transmute_ref(&bytes);
+    /// ```
+    #[inline(always)]
+    pub const fn transmute_ref<'a, Src, Dst, Neglect>(src: &'a Src) -> &'a Dst
+    where
+        Src: ?Sized,
+        Dst: ?Sized,
+        &'a Src: TransmuteInto<&'a Dst, Neglect>,
+        Neglect: SafeTransmuteOptions
+    {
+        safe_transmute::<&'a Src, &'a Dst, Neglect>(src)
+    }
+
+    /// Reinterprets a mutable reference to one type as a mutable reference to another type, safely.
+    ///
+    /// This is shorthand for [safe_transmute] on mutable references; e.g.:
+    /// ```rust
+    /// let header: &mut Header = transmute_mut(&mut bytes);
+    /// ```
+    #[inline(always)]
+    pub fn transmute_mut<'a, Src, Dst, Neglect>(src: &'a mut Src) -> &'a mut Dst
+    where
+        Src: ?Sized,
+        Dst: ?Sized,
+        &'a mut Src: TransmuteInto<&'a mut Dst, Neglect>,
+        Neglect: SafeTransmuteOptions
+    {
+        src.transmute_into()
+    }
+
+    /// (Extension) Reinterprets the bits of a value of one type as another type.
+    ///
+    /// This macro is shorthand for [safe_transmute] and [unsafe_transmute] that spares call sites the turbofish the trait API otherwise requires; e.g.:
+    /// ```rust
+    /// let packet = TransmuteInto::<Packet, (NeglectStability,)>::transmute_into(bytes);
+    /// ```
+    /// may be written as:
+    /// ```rust
+    /// let packet = transmute!(bytes => Packet, neglect = (NeglectStability,));
+    /// ```
+    ///
+    /// The destination type may be omitted if it can be inferred:
+    /// ```rust
+    /// let packet: Packet = transmute!(bytes);
+    /// ```
+    ///
+    /// If `neglect` is omitted, no static checks are neglected. If neglected checks include any of [NeglectAlignment], [NeglectValidity], [NeglectSize] or [NeglectProvenance], prefix the expression with `unsafe` to select the unsafe transmutation path. As with [unsafe_transmute], the onus is on you to ensure the result is safe, and the macro must be invoked within an `unsafe` block:
+    /// ```rust
+    /// let flag: bool = unsafe { transmute!(unsafe byte => bool, neglect = NeglectValidity) };
+    /// ```
+    #[unstable(feature = "transmute_macro", issue = "none")]
+    pub macro transmute {
+        (unsafe $src:expr => $dst:ty, neglect = $neglect:ty) => {
+            $crate::transmute::unsafe_transmute::<_, $dst, $neglect>($src)
+        },
+        (unsafe $src:expr, neglect = $neglect:ty) => {
+            $crate::transmute::unsafe_transmute::<_, _, $neglect>($src)
+        },
+        ($src:expr => $dst:ty, neglect = $neglect:ty) => {
+            $crate::transmute::safe_transmute::<_, $dst, $neglect>($src)
+        },
+        ($src:expr => $dst:ty) => {
+            $crate::transmute::safe_transmute::<_, $dst, ()>($src)
+        },
+        ($src:expr, neglect = $neglect:ty) => {
+            $crate::transmute::safe_transmute::<_, _, $neglect>($src)
+        },
+        ($src:expr) => {
+            $crate::transmute::safe_transmute::<_, _, ()>($src)
+        },
+    }
+
+    /// Reinterpret the bits of `Self` as a type `Dst`.
+    ///
+    /// The reciprocal of [TransmuteFrom].
+    ///
+    /// ***This trait is implemented automatically by the compiler for combinations of types where a transmutation is valid.***
+    pub unsafe trait TransmuteInto<Dst: ?Sized, Neglect = ()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {
+        /// Reinterpret the bits of a value of one type as another type, safely.
+        fn transmute_into(self) -> Dst
+        where
+            Self: Sized,
+            Dst: Sized,
+            Neglect: SafeTransmuteOptions;
+
+        /// Reinterpret the bits of a value of one type as another type, potentially unsafely.
+        ///
+        /// The onus is on you to ensure that calling this method is safe.
+        unsafe fn unsafe_transmute_into(self) -> Dst
+        where
+            Self: Sized,
+            Dst: Sized,
+            Neglect: UnsafeTransmuteOptions;
+    }
+
+    unsafe impl<Src, Dst, Neglect> TransmuteInto<Dst, Neglect> for Src
+    where
+        Src: ?Sized,
+        Dst: ?Sized + TransmuteFrom<Src, Neglect>,
+        Neglect: UnsafeTransmuteOptions,
+    {
+        #[inline(always)]
+        fn transmute_into(self) -> Dst
+        where
+            Self: Sized,
+            Dst: Sized,
+            Neglect: SafeTransmuteOptions,
+        {
+            Dst::transmute_from(self)
+        }
+
+        #[inline(always)]
+        unsafe fn unsafe_transmute_into(self) -> Dst
+        where
+            Self: Sized,
+            Dst: Sized,
+            Neglect: UnsafeTransmuteOptions,
+        {
+            unsafe { Dst::unsafe_transmute_from(self) }
+        }
+    }
+
+    /// Reinterpret the bits of `Src` as a type `Self`.
+    ///
+    /// The reciprocal of [TransmuteFrom].
+    ///
+    /// ***This trait is implemented automatically by the compiler for combinations of types where a transmutation is valid.***
+    ///
+    /// A transmutation is valid if it passes each of the following static checks, except those neglected by the options `Neglect`:
+    ///  - **Stability** ([NeglectStability]): the layouts of `Src` and `Self` are promised to be SemVer-stable.
+    ///  - **Alignment** ([NeglectAlignment]): for references, the referent of `Self` is no more aligned than the referent of `Src`.
+    ///  - **Validity** ([NeglectValidity]): every bit-valid instance of `Src` is a bit-valid instance of `Self`.
+    ///  - **Size** ([NeglectSize]): `Self` is no larger than `Src`.
+    ///  - **Provenance** ([NeglectProvenance]): no pointer bytes of `Src` are reinterpreted as non-pointer bytes of `Self`, nor non-pointer bytes of `Src` as pointer bytes of `Self`. Transmutations between integers and pointers, like `usize` to `*const T`, are therefore rejected unless provenance is neglected, even though their layouts are identical.
+    pub unsafe trait TransmuteFrom<Src: ?Sized, Neglect = ()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {
+        /// Reinterpret the bits of a value of one type as another type, safely.
+        #[inline(always)]
+        fn transmute_from(src: Src) -> Self
+        where
+            Src: Sized,
+            Self: Sized,
+            Neglect: SafeTransmuteOptions,
+        {
+            unsafe { ManuallyDrop::into_inner(Transmute { src: ManuallyDrop::new(src) }.dst) }
+        }
+
+        /// Reinterpret the bits of a value of one type as another type, potentially unsafely.
+        ///
+        /// The onus is on you to ensure that calling this function is safe.
+        #[inline(always)]
+        unsafe fn unsafe_transmute_from(src: Src) -> Self
+        where
+            Src: Sized,
+            Self: Sized,
+            Neglect: UnsafeTransmuteOptions,
+        {
+            // The union is as large as the larger of `Src` and `Self`, so the
+            // tail of a widening transmutation (see `NeglectSize`) is left
+            // uninitialized, rather than read from beyond the end of `src`.
+            unsafe { ManuallyDrop::into_inner(Transmute { src: ManuallyDrop::new(src) }.dst) }
+        }
+    }
+
+    #[doc(hidden)]
+    unsafe impl<T> TransmuteFrom<T, NeglectStability> for T {}
+
+    /// A mutable reference may be downgraded to a shared reference of any type that a shared reference to its referent could be transmuted into.
+    ///
+    /// Generic code therefore needs no separate bounds for each combination of mutabilities; e.g.:
+    /// ```rust
+    /// let mut words = [0u16; 2];
+    /// let bytes: &[u8; 4] = (&mut words).transmute_into(); // `&mut [u16; 2]` into `&[u8; 4]`
+    /// ```
+    /// Since the source's referent remains exclusively borrowed for the lifetime of the result, only the transmutability of the referents matters, exactly as with `&T` to `&U`. With the default options, this impl applies via the [stability declarations][stability] of `&mut T` and `&U`.
+    unsafe impl<'a, Src, Dst> TransmuteFrom<&'a mut Src, NeglectStability> for &'a Dst
+    where
+        Src: ?Sized,
+        Dst: ?Sized,
+        &'a Dst: TransmuteFrom<&'a Src, NeglectStability>,
+    {}
+
+    /// A type `Dst` is [stably][stability] transmutable from `Src` if:
+    ///  - `Dst` implements [PromiseTransmutableFrom][trait@PromiseTransmutableFrom], 
+    ///  - `Src` implements [PromiseTransmutableInto][trait@PromiseTransmutableInto], and
+    ///  - The [PromiseTransmutableFrom::Archetype] of `Dst` is safely transmutable from the [PromiseTransmutableInto::Archetype] of `Src`.
+    unsafe impl<Src, Dst> TransmuteFrom<Src> for Dst
+    where
+        Src: PromiseTransmutableInto,
+        Dst: PromiseTransmutableFrom,
+        <Dst as PromiseTransmutableFrom>::Archetype:
+            TransmuteFrom<
+                <Src as PromiseTransmutableInto>::Archetype,
+                NeglectStability
+            >
+    {}
+
+    /// (Extension) A value produced by a safe transmutation.
+    ///
+    /// An implementation of [From] in terms of [TransmuteFrom] is impossible, even for this newtype, as `impl<Src, Dst> From<Src> for Transmuted<Dst>` would overlap with the standard library's reflexive `impl<T> From<T> for T` at `Src = Transmuted<Dst>`. Instead, `Transmuted<Dst>` is constructed from any `Src` from which `Dst` is safely transmutable with [Transmuted::new], and generic code names that requirement with a [TransmuteInto] bound; e.g.:
+    /// ```rust
+    /// fn checksum<T: TransmuteInto<[u8; 16]>>(val: T) -> u8 {
+    ///     Transmuted::new(val).into_inner().iter().fold(0, |a, b| a ^ b)
+    /// }
+    ///
+    /// checksum(0xdead_beef_u128);
+    /// ```
+    #[unstable(feature = "transmuted", issue = "none")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    #[repr(transparent)]
+    pub struct Transmuted<T>(pub T);
+
+    #[unstable(feature = "transmuted", issue = "none")]
+    impl<T> Transmuted<T> {
+        /// Safely transmute `src` into a `T`.
+        #[inline(always)]
+        pub fn new<Src>(src: Src) -> Self
+        where
+            T: TransmuteFrom<Src>,
+        {
+            Transmuted(T::transmute_from(src))
+        }
+
+        /// Unwraps the transmuted value.
+        #[inline(always)]
+        pub fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    /// Traits for declaring the SemVer stability of a type's layout.
+    ///
+    /// Since the soundness and safety of a transmutation is affected by the layouts of the source and destination types, changes to those types' layouts may cause code which previously compiled to produce errors. In other words, transmutation causes a type's layout to become part of that type's API for the purposes of SemVer stability.
+    /// 
+    /// To promise that all transmutations which are currently safe for your type will remain so in the future, simply annotate your type with:
+    /// ```rust
+    /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+    /// #[repr(C)]
+    /// pub struct Foo(pub Bar, pub Baz);
+    /// ```
+    ///
+    /// For more information on stability, [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#-when-is-a-transmutation-stable).
+    pub mod stability {
+
+        use super::{TransmuteFrom, TransmuteInto, options::NeglectStability};
+
+        /// Promise that a type may be stably transmuted *into* other types.
+        ///
+        /// To promise that all safe transmutations from your type into other `PromiseTransmutableFrom` types will remain safe in the future, simply annotate your type with `#[derive(PromiseTransmutableFrom)]`; e.g.:
+        /// ```rust
+        /// #[derive(PromiseTransmutableFrom)]
+        /// #[repr(C)]
+        /// pub struct Foo(pub Bar, pub Baz);
+        /// ```
+        pub trait PromiseTransmutableInto
+        {
+            /// A type which exemplifies the greatest extent to which `Self` might change in non-breaking crate releases, insofar that those changes might affect converting `Self` into another type via transmutation. 
+            type Archetype
+                : TransmuteFrom<Self, NeglectStability>
+                + PromiseTransmutableInto;
+        }
+
+        /// Promise that a type may be stably transmuted *from* other types.
+        ///
+        /// To promise that all transmutations of any `PromiseTransmutableInto` type into your type that are currently safe will remain so in the future, simply annotate your type with `#[derive(PromiseTransmutableFrom)]`.
+        /// 
+        /// For instance, this:
+        /// ```rust
+        /// #[derive(PromiseTransmutableFrom)]
+        /// #[repr(C)]
+        /// pub struct Foo(pub Bar, pub Baz);
+        /// ```
+        /* #[lang = "promise_transmutable_from"] */
+        pub trait PromiseTransmutableFrom
+        {
+            /// A type which exemplifies the greatest extent to which `Self` might change in non-breaking crate releases, insofar that those changes might affect instantiating `Self` via transmutation. 
+            type Archetype
+                : TransmuteInto<Self, NeglectStability>
+                + PromiseTransmutableFrom;
+        }
+
+        #[doc(hidden)]
+        mod macros {
+            use super::*;
+
+            /// Derive macro generating an impl of the trait [PromiseTransmutableFrom][trait@PromiseTransmutableFrom].
+            ///
+            /// To promise that all safe transmutations from your type into other `PromiseTransmutableFrom` types will remain safe in the future, simply annotate your type with `#[derive(PromiseTransmutableFrom)]`.
+            /// 
+            /// For instance, this:
+            /// ```rust
+            /// #[derive(PromiseTransmutableFrom)]
+            /// #[repr(C)]
+            /// pub struct Foo(pub Bar, pub Baz);
+            /// ```
+            /// will expand to this:
+            /// ```rust
+            /// /// Generated `PromiseTransmutableInto` for `Foo`
+            /// const _: () = {
+            ///     use core::convert::transmute::stability::PromiseTransmutableInto;
+            /// 
+            ///     #[repr(C)]
+            ///     pub struct TransmutableIntoArchetype(
+            ///         pub <Bar as PromiseTransmutableInto>::Archetype,
+            ///         pub <Baz as PromiseTransmutableInto>::Archetype,
+            ///     );
+            /// 
+            ///     impl PromiseTransmutableInto for TransmutableIntoArchetype { type Archetype = Self };
+            /// 
+            ///     impl PromiseTransmutableInto for Foo {
+            ///         type Archetype = TransmutableIntoArchetype;
+            ///     }
+            /// };
+            /// ```
+            ///
+            /// Runs of adjacent fields of the same primitive type are collapsed into a single array field of the archetype, since `N` adjacent `u8`s are laid out exactly as a `[u8; N]`. This keeps the archetypes of large and deeply composed types small, and their promises quick to check. For instance, this:
+            /// ```rust
+            /// #[derive(PromiseTransmutableInto)]
+            /// #[repr(C)]
+            /// pub struct Pixel { pub r: u8, pub g: u8, pub b: u8, pub a: u8, pub depth: f32 }
+            /// ```
+            /// will expand to an archetype with just two fields:
+            /// ```rust
+            /// #[repr(C)]
+            /// pub struct TransmutableIntoArchetype(
+            ///     pub [u8; 4],
+            ///     pub f32,
+            /// );
+            /// ```
+            ///
+            /// Enums with fields may also be derived, if they have a primitive representation (e.g., `#[repr(u8)]`) or a C-compatible tagged representation (e.g., `#[repr(C, u8)]`). The archetype mirrors the [documented layout](https://rust-lang.github.io/rfcs/2195-really-tagged-unions.html) of such enums, with each variant's fields replaced by their archetypes. For instance, this:
+            /// ```rust
+            /// #[derive(PromiseTransmutableInto)]
+            /// #[repr(C, u8)]
+            /// pub enum Message { Ping, Data(Len, [u8; 60]) }
+            /// ```
+            /// will expand to an archetype consisting of a tag followed by a union of the variants' payloads:
+            /// ```rust
+            /// #[repr(u8)]
+            /// pub enum TransmutableIntoArchetypeTag { Ping = 0, Data = 1 }
+            ///
+            /// #[repr(C)]
+            /// pub struct TransmutableIntoArchetypePing;
+            ///
+            /// #[repr(C)]
+            /// pub struct TransmutableIntoArchetypeData(
+            ///     pub <Len as PromiseTransmutableInto>::Archetype,
+            ///     pub [u8; 60],
+            /// );
+            ///
+            /// #[repr(C)]
+            /// pub union TransmutableIntoArchetypePayload {
+            ///     pub Ping: ManuallyDrop<TransmutableIntoArchetypePing>,
+            ///     pub Data: ManuallyDrop<TransmutableIntoArchetypeData>,
+            /// }
+            ///
+            /// #[repr(C)]
+            /// pub struct TransmutableIntoArchetype(
+            ///     pub TransmutableIntoArchetypeTag,
+            ///     pub TransmutableIntoArchetypePayload,
+            /// );
+            /// ```
+            /// For `#[repr(u8)]` enums, the tag is instead the first field of each variant's struct, and the archetype is the union of those structs. Each of the generated types promises that its layout is stable, with an archetype of `Self`.
+            ///
+            /// By default, the archetype is declared in an anonymous `const` item, so it cannot be named outside of the expansion. The `#[promise_transmutable(...)]` attribute instead declares it alongside your type, with the given name and visibility, so that it may be documented, re-exported, or named in downstream bounds:
+            /// ```rust
+            /// #[derive(PromiseTransmutableInto)]
+            /// #[promise_transmutable(into_archetype = "FooInto", archetype_vis = "pub")]
+            /// #[repr(C)]
+            /// pub struct Foo(pub Bar, pub Baz);
+            /// ```
+            /// will expand to:
+            /// ```rust
+            /// /// The `PromiseTransmutableInto` archetype of [Foo].
+            /// #[repr(C)]
+            /// pub struct FooInto(
+            ///     pub <Bar as PromiseTransmutableInto>::Archetype,
+            ///     pub <Baz as PromiseTransmutableInto>::Archetype,
+            /// );
+            ///
+            /// impl PromiseTransmutableInto for FooInto { type Archetype = Self };
+            ///
+            /// impl PromiseTransmutableInto for Foo {
+            ///     type Archetype = FooInto;
+            /// }
+            /// ```
+            /// If `archetype_vis` is omitted, the archetype has the visibility of your type. The types generated for enums with fields are named by suffixing the given name, as above.
+            ///
+            /// The generated impl is bounded only by what the archetype needs: each field's type must implement `PromiseTransmutableInto`. Type parameters that appear only within [PhantomData][core::marker::PhantomData] (or other zero-sized, layout-independent positions, like `[T; 0]`) never affect layout, and so incur no bounds. For instance, this:
+            /// ```rust
+            /// #[derive(PromiseTransmutableInto)]
+            /// #[repr(C)]
+            /// pub struct Tagged<T> { pub id: u32, pub _marker: PhantomData<T> }
+            /// ```
+            /// will expand to an impl for *every* `T`:
+            /// ```rust
+            /// impl<T> PromiseTransmutableInto for Tagged<T> {
+            ///     type Archetype = TransmutableIntoArchetype;
+            /// }
+            /// ```
+            /// where the archetype's marker field is `PhantomData<()>`.
+            ///
+            /// Where these inferred bounds are too strict or too loose, `#[promise_transmutable(bound = "...")]` replaces the generated where-clause, as with serde's `#[serde(bound)]`; e.g.:
+            /// ```rust
+            /// #[derive(PromiseTransmutableInto)]
+            /// #[promise_transmutable(bound = "T: Copy + PromiseTransmutableInto")]
+            /// #[repr(transparent)]
+            /// pub struct Handle<T>(pub Opaque<T>);
+            /// ```
+            /// An empty bound, `bound = ""`, removes the where-clause entirely. Since the archetype must still be transmutable from your type, an overly loose bound merely produces an error at the use site, rather than an unsound impl. The bounds of [PromiseTransmutableFrom!] and [PromiseTransmutableInto!] may be overridden separately with `from_bound` and `into_bound`.
+            pub macro PromiseTransmutableInto($item:item) {
+                /* compiler built-in */
+            }
+
+            /// Derive macro generating an impl of the trait [PromiseTransmutableFrom][trait@PromiseTransmutableFrom].
+            ///
+            /// To promise that all transmutations of any `PromiseTransmutableInto` type into your type that are currently safe will remain so in the future, simply annotate your type with `#[derive(PromiseTransmutableFrom)]`.
+            /// 
+            /// For instance, this:
+            /// ```rust
+            /// #[derive(PromiseTransmutableFrom)]
+            /// #[repr(C)]
+            /// pub struct Foo(pub Bar, pub Baz);
+            /// ```
+            /// will expand to this:
+            /// ```rust
+            /// /// Generated `PromiseTransmutableFrom` for `Foo`
+            /// const _: () = {
+            ///     use core::convert::transmute::stability::PromiseTransmutableFrom;
+            /// 
+            ///     #[repr(C)]
+            ///     pub struct TransmutableFromArchetype(
+            ///         pub <Bar as PromiseTransmutableFrom>::Archetype,
+            ///         pub <Baz as PromiseTransmutableFrom>::Archetype,
+            ///     );
+            /// 
+            ///     impl PromiseTransmutableFrom for TransmutableFromArchetype { type Archetype = Self };
+            /// 
+            ///     impl PromiseTransmutableFrom for Foo {
+            ///         type Archetype = TransmutableFromArchetype;
+            ///     }
+            /// };
+            /// ```
+            ///
+            /// As with [PromiseTransmutableInto!], runs of adjacent fields of the same primitive type are collapsed into a single array field of the archetype, and enums with fields and a primitive or C-compatible tagged representation are supported.
+            ///
+            /// The archetype may likewise be named with `#[promise_transmutable(from_archetype = "...")]`, and its visibility chosen with `archetype_vis`.
+            ///
+            /// As with [PromiseTransmutableInto!], type parameters used only within `PhantomData` incur no bounds.
+            ///
+            /// Transmuting a field-less `#[repr(u8)]` enum *into* a `u8` is always sound, and remains so under any change to the enum that keeps its `repr`; only the `u8 -> Enum` direction depends on which variants exist. Accordingly, on such enums this derive *also* generates `PromiseTransmutableInto`, with the enum's primitive representation as its archetype, so that authors need only opt into the direction that constrains them. For instance, this:
+            /// ```rust
+            /// #[derive(PromiseTransmutableFrom)]
+            /// #[repr(u8)]
+            /// pub enum State { Idle = 0, Busy = 1, Done = 2 }
+            /// ```
+            /// will additionally expand to:
+            /// ```rust
+            /// impl PromiseTransmutableInto for State { type Archetype = u8; }
+            /// impl PrimitiveRepr for State { type Repr = u8; }
+            /// ```
+            /// Deriving [PromiseTransmutableInto!] alongside is permitted, and generates nothing further for such enums.
+            ///
+            /// Newtypes of a primitive integer may restrict their valid values with the `#[valid_range(...)]` attribute. The archetype then carries the range as a scalar validity invariant, so only transmutations that provably stay within it are accepted statically; the derive also generates a matching [CheckBitValidity][crate::validity::CheckBitValidity] implementation, with which the others may be checked at runtime. For instance, this:
+            /// ```rust
+            /// #[derive(PromiseTransmutableFrom)]
+            /// #[valid_range(0..=100)]
+            /// #[repr(transparent)]
+            /// pub struct Percent(u8);
+            /// ```
+            /// will expand to:
+            /// ```rust
+            /// const _: () = {
+            ///     use core::convert::transmute::{stability::PromiseTransmutableFrom, validity::CheckBitValidity};
+            ///
+            ///     #[repr(transparent)]
+            ///     #[rustc_layout_scalar_valid_range_start(0)]
+            ///     #[rustc_layout_scalar_valid_range_end(100)]
+            ///     pub struct TransmutableFromArchetype(pub u8);
+            ///
+            ///     impl PromiseTransmutableFrom for TransmutableFromArchetype { type Archetype = Self };
+            ///
+            ///     impl PromiseTransmutableFrom for Percent {
+            ///         type Archetype = TransmutableFromArchetype;
+            ///     }
+            ///
+            ///     unsafe impl CheckBitValidity for Percent {
+            ///         fn is_bit_valid(bytes: &[u8]) -> bool {
+            ///             (0..=100).contains(&u8::from_ne_bytes([bytes[0]]))
+            ///         }
+            ///     }
+            /// };
+            /// ```
+            /// so that `u8 -> Percent` requires [NeglectValidity][crate::transmute::options::NeglectValidity] and a runtime check (e.g., with [try_transmute][crate::validity::try_transmute]), while `bool -> Percent` is accepted as is. The range must be inclusive and lie within the integer type; the attribute is rejected on any other type. Like the range attributes it lowers to, it also gives the type a niche, so `Option<Percent>` has the size of a `u8`.
+            pub macro PromiseTransmutableFrom($item:item) {
+                /* compiler built-in */
+            }
+
+            /// Derive macro generating impls of *both* [PromiseTransmutableFrom][trait@PromiseTransmutableFrom] and [PromiseTransmutableInto][trait@PromiseTransmutableInto].
+            ///
+            /// This is just a shorthand for deriving both [PromiseTransmutableFrom!] and [PromiseTransmutableInto!].
+            ///
+            /// For more information on this extension, [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#extension-promisetransmutable-shorthand).
+            #[unstable(feature = "stability_shorthand", issue = "none")]
+            pub macro PromiseTransmutable($item:item) {
+                /* compiler built-in */
+            }
+        }
+
+        #[doc(inline)]
+        pub use macros::{
+            PromiseTransmutableInto,
+            PromiseTransmutableFrom,
+            PromiseTransmutable,
+        };
+
+        /// Promise that the layouts of types are stable, with an archetype of `Self`.
+        ///
+        /// This expands to the same pair of impls as written for the primitive types; e.g., this:
+        /// ```rust
+        /// impl_promise_reflexive!(Pixel, Voxel);
+        /// ```
+        /// will expand to this:
+        /// ```rust
+        /// impl PromiseTransmutableInto for Pixel { type Archetype = Self; }
+        /// impl PromiseTransmutableFrom for Pixel { type Archetype = Self; }
+        /// impl PromiseTransmutableInto for Voxel { type Archetype = Self; }
+        /// impl PromiseTransmutableFrom for Voxel { type Archetype = Self; }
+        /// ```
+        /// Like the derives, this places every field of each type in its public API; unlike them, it does not require that those fields are public.
+        pub macro impl_promise_reflexive($($ty:ty),* $(,)?) {
+            $(
+                impl $crate::transmute::stability::PromiseTransmutableInto for $ty { type Archetype = Self; }
+                impl $crate::transmute::stability::PromiseTransmutableFrom for $ty { type Archetype = Self; }
+            )*
+        }
+
+        impl PromiseTransmutableInto for     ! {type Archetype = Self;}
+        impl PromiseTransmutableFrom for     ! {type Archetype = Self;}
+
+        impl PromiseTransmutableInto for    () {type Archetype = Self;}
+        impl PromiseTransmutableFrom for    () {type Archetype = Self;}
+
+        impl PromiseTransmutableInto for   f32 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   f32 {type Archetype = Self;}
+        impl PromiseTransmutableInto for   f64 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   f64 {type Archetype = Self;}
+
+        impl PromiseTransmutableInto for    i8 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for    i8 {type Archetype = Self;}
+        impl PromiseTransmutableInto for   i16 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   i16 {type Archetype = Self;}
+        impl PromiseTransmutableInto for   i32 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   i32 {type Archetype = Self;}
+        impl PromiseTransmutableInto for   i64 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   i64 {type Archetype = Self;}
+        impl PromiseTransmutableInto for  i128 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for  i128 {type Archetype = Self;}
+
+        impl PromiseTransmutableInto for    u8 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for    u8 {type Archetype = Self;}
+        impl PromiseTransmutableInto for   u16 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   u16 {type Archetype = Self;}
+        impl PromiseTransmutableInto for   u32 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   u32 {type Archetype = Self;}
+        impl PromiseTransmutableInto for   u64 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   u64 {type Archetype = Self;}
+        impl PromiseTransmutableInto for  u128 {type Archetype = Self;}
+        impl PromiseTransmutableFrom for  u128 {type Archetype = Self;}
+
+        /// Pointer-sized integers are laid out exactly as `u16`, `u32` or `u64`, depending on the target's pointer width. Consequently, whether, e.g., `usize: TransmuteInto<u64>` holds is platform-dependent: it holds on 64-bit targets, and is a compile error elsewhere. Code that must be portable should not rely on it.
+        impl PromiseTransmutableInto for usize {type Archetype = Self;}
+        /// See [usize's `PromiseTransmutableInto` implementation][PromiseTransmutableInto#impl-PromiseTransmutableInto-for-usize].
+        impl PromiseTransmutableFrom for usize {type Archetype = Self;}
+        /// See [usize's `PromiseTransmutableInto` implementation][PromiseTransmutableInto#impl-PromiseTransmutableInto-for-usize].
+        impl PromiseTransmutableInto for isize {type Archetype = Self;}
+        /// See [usize's `PromiseTransmutableInto` implementation][PromiseTransmutableInto#impl-PromiseTransmutableInto-for-usize].
+        impl PromiseTransmutableFrom for isize {type Archetype = Self;}
+
+        use core::num::*;
+
+        macro_rules! nonzero_promises {
+            ($($nonzero:ident),* $(,)?) => {$(
+                impl PromiseTransmutableInto for $nonzero {type Archetype = Self;}
+                impl PromiseTransmutableFrom for $nonzero {type Archetype = Self;}
+
+                /// The standard library guarantees that `Option<NonZero*>` is laid out as its integer, with `None` represented as `0`. Every integer is thereby a valid `Option<NonZero*>`, and every `Option<NonZero*>` a valid integer, so it is transmutable both into and from its integer:
+                /// ```rust
+                /// let none: Option<NonZeroU32> = 0u32.transmute_into();
+                /// assert_eq!(none, None);
+                ///
+                /// let bits: u32 = NonZeroU32::new(7).transmute_into();
+                /// assert_eq!(bits, 7);
+                /// ```
+                impl PromiseTransmutableInto for Option<$nonzero> {type Archetype = Self;}
+                /// See [the `PromiseTransmutableInto` implementation][PromiseTransmutableInto#impl-PromiseTransmutableInto-for-Option<NonZeroU32>].
+                impl PromiseTransmutableFrom for Option<$nonzero> {type Archetype = Self;}
+            )*}
+        }
+
+        nonzero_promises! {
+            NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+            NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+        }
+
+        use core::marker::PhantomData;
+        impl<T: ?Sized> PromiseTransmutableInto for PhantomData<T> { type Archetype = Self; }
+        impl<T: ?Sized> PromiseTransmutableFrom for PhantomData<T> { type Archetype = Self; }
+
+
+        /// The archetype of `[T; N]` is `[T::Archetype; N]`.
+        ///
+        /// These impls need no chunking or special-casing of large `N`: none of their bounds is stated per element. The transmutability bound names the arrays as a whole, and is answered by one query of the compiler's layout check; the promise bound on `[T::Archetype; N]` recurses only along the chain of archetypes, whose length is independent of `N`. Checking a promise on a type containing `[T; 4096]` therefore incurs the same obligations as one containing `[T; 1]`.
+        impl<T, const N: usize> PromiseTransmutableInto for [T; N]
+        where
+            T: PromiseTransmutableInto,
+            [T::Archetype; N]
+                : TransmuteFrom<Self, NeglectStability>
+                + PromiseTransmutableInto,
+        {
+            type Archetype = [T::Archetype; N];
+        }
+
+        /// The archetype of `[T; N]` is `[T::Archetype; N]`.
+        ///
+        /// As with [PromiseTransmutableInto], no bound of this impl is stated per element, so its obligations do not grow with `N`.
+        impl<T, const N: usize> PromiseTransmutableFrom for [T; N]
+        where
+            T: PromiseTransmutableFrom,
+            [T::Archetype; N]
+                : TransmuteInto<Self, NeglectStability>
+                + PromiseTransmutableFrom,
+        {
+            type Archetype = [T::Archetype; N];
+        }
+
+
+        use core::mem::MaybeUninit;
+
+        impl<T> PromiseTransmutableInto for MaybeUninit<T>
+        where
+            T: PromiseTransmutableInto,
+            MaybeUninit<T::Archetype>
+                : TransmuteFrom<Self, NeglectStability>
+                + PromiseTransmutableInto,
+        {
+            type Archetype = MaybeUninit<T::Archetype>;
+        }
+
+        impl<T> PromiseTransmutableFrom for MaybeUninit<T>
+        where
+            T: PromiseTransmutableFrom,
+            MaybeUninit<T::Archetype>
+                : TransmuteInto<Self, NeglectStability>
+                + PromiseTransmutableFrom,
+        {
+            type Archetype = MaybeUninit<T::Archetype>;
+        }
+
+
+        impl<T: ?Sized> PromiseTransmutableInto for *const T
+        where
+            T: PromiseTransmutableInto,
+            *const T::Archetype
+                : TransmuteFrom<Self, NeglectStability>
+                + PromiseTransmutableInto,
+        {
+            type Archetype = *const T::Archetype;
+        }
+
+        impl<T: ?Sized> PromiseTransmutableFrom for *const T
+        where
+            T: PromiseTransmutableFrom,
+            *const T::Archetype
+                : TransmuteInto<Self, NeglectStability>
+                + PromiseTransmutableFrom,
+        {
+            type Archetype = *const T::Archetype;
+        }
+
+
+        impl<T: ?Sized> PromiseTransmutableInto for *mut T
+        where
+            T: PromiseTransmutableInto,
+            *mut T::Archetype
+                : TransmuteFrom<Self, NeglectStability>
+                + PromiseTransmutableInto,
+        {
+            type Archetype = *mut T::Archetype;
+        }
+
+        impl<T: ?Sized> PromiseTransmutableFrom for *mut T
+        where
+            T: PromiseTransmutableFrom,
+            *mut T::Archetype
+                : TransmuteInto<Self, NeglectStability>
+                + PromiseTransmutableFrom,
+        {
+            type Archetype = *mut T::Archetype;
+        }
+
+
+        /// The archetype of `*const [T]` is `*const [T::Archetype]`.
+        ///
+        /// Pointers to slices are wide: their metadata is the length of the slice, which is unaffected by transmuting the elements. Unsafe data structures that store them (e.g., a custom `Vec`) may therefore derive promises for their containers.
+        impl<T> PromiseTransmutableInto for *const [T]
+        where
+            T: PromiseTransmutableInto,
+            *const [T::Archetype]
+                : TransmuteFrom<Self, NeglectStability>
+                + PromiseTransmutableInto,
+        {
+            type Archetype = *const [T::Archetype];
+        }
+
+        /// The archetype of `*const [T]` is `*const [T::Archetype]`.
+        impl<T> PromiseTransmutableFrom for *const [T]
+        where
+            T: PromiseTransmutableFrom,
+            *const [T::Archetype]
+                : TransmuteInto<Self, NeglectStability>
+                + PromiseTransmutableFrom,
+        {
+            type Archetype = *const [T::Archetype];
+        }
+
+        /// The archetype of `*mut [T]` is `*mut [T::Archetype]`.
+        impl<T> PromiseTransmutableInto for *mut [T]
+        where
+            T: PromiseTransmutableInto,
+            *mut [T::Archetype]
+                : TransmuteFrom<Self, NeglectStability>
+                + PromiseTransmutableInto,
+        {
+            type Archetype = *mut [T::Archetype];
+        }
+
+        /// The archetype of `*mut [T]` is `*mut [T::Archetype]`.
+        impl<T> PromiseTransmutableFrom for *mut [T]
+        where
+            T: PromiseTransmutableFrom,
+            *mut [T::Archetype]
+                : TransmuteInto<Self, NeglectStability>
+                + PromiseTransmutableFrom,
+        {
+            type Archetype = *mut [T::Archetype];
+        }
+
+        impl PromiseTransmutableInto for *const str {type Archetype = Self;}
+        impl PromiseTransmutableFrom for *const str {type Archetype = Self;}
+        impl PromiseTransmutableInto for   *mut str {type Archetype = Self;}
+        impl PromiseTransmutableFrom for   *mut str {type Archetype = Self;}
+
+
+        impl<'a, T: ?Sized> PromiseTransmutableInto for &'a T
+        where
+            T: PromiseTransmutableInto,
+            &'a T::Archetype
+                : TransmuteFrom<&'a T, NeglectStability>
+                + PromiseTransmutableInto,
+        {
+            type Archetype = &'a T::Archetype;
+        }
+
+        impl<'a, T: ?Sized> PromiseTransmutableFrom for &'a T
+        where
+            T: PromiseTransmutableFrom,
+            &'a T::Archetype
+                : TransmuteInto<&'a T, NeglectStability>
+                + PromiseTransmutableFrom,
+        {
+            type Archetype = &'a T::Archetype;
+        }
+
+        impl<'a, T: ?Sized> PromiseTransmutableInto for &'a mut T
+        where
+            T: PromiseTransmutableInto,
+            &'a mut T::Archetype
+                : TransmuteFrom<&'a mut T, NeglectStability>
+                + PromiseTransmutableInto,
+        {
+            type Archetype = &'a mut T::Archetype;
+        }
+
+        impl<'a, T: ?Sized> PromiseTransmutableFrom for &'a mut T
+        where
+            T: PromiseTransmutableFrom,
+            &'a mut T::Archetype
+                : TransmuteInto<&'a mut T, NeglectStability>
+                + PromiseTransmutableFrom,
+        {
+            type Archetype = &'a mut T::Archetype;
+        }
+    }
+
+    /// Static checks that may be neglected when determining if two types are transmutable.
+    ///
+    /// The default value of the `Neglect` parameter of [TransmuteFrom] and [TransmuteInto], `()`, statically forbids transmutes that are unsafe, unsound, or unstable. However, you may explicitly opt-out of some static checks:
+    /// 
+    /// | Transmute Option    | Compromises | Usable With                                             |
+    /// |---------------------|-------------|---------------------------------------------------------|
+    /// | [NeglectStability]  | Stability   | `transmute_{from,into}`, `unsafe_transmute_{from,into}` |
+    /// | [NeglectAlignment]  | Safety      | `unsafe_transmute_{from,into}`                          |
+    /// | [NeglectValidity]   | Soundness   | `unsafe_transmute_{from,into}`                          |
+    /// | [NeglectSize]       | Safety      | `unsafe_transmute_{from,into}`                          |
+    /// | [NeglectProvenance] | Safety      | `unsafe_transmute_{from,into}`                          |
+    /// 
+    /// The selection of multiple options is encoded by grouping them as a tuple; e.g., `(NeglectAlignment, NeglectValidity)` is a selection of both the [NeglectAlignment] and [NeglectValidity] options. Each option may be selected at most once, and options are listed in the order in which they are documented below; a selection like `(NeglectValidity, NeglectValidity)` or `(NeglectValidity, NeglectAlignment)` is rejected at compile time.
+    pub mod options {
+        use super::*;
+
+        /// Options that may be used with safe transmutations.
+        #[rustc_on_unimplemented(
+            message = "`{Self}` may not be used with safe transmutations",
+            note = "only `NeglectStability` may be neglected by safe transmutations",
+        )]
+        pub trait SafeTransmuteOptions: UnsafeTransmuteOptions
+        {}
+
+        /// Options that may be used with unsafe transmutations.
+        ///
+        /// The associated constants of this trait describe which static checks a selection of options neglects, so that generic code may log, explain, or enforce a policy over whichever options its caller selected; e.g.:
+        /// ```rust
+        /// fn audited_transmute<Src, Dst, Neglect>(src: Src) -> Dst
+        /// where
+        ///     Src: TransmuteInto<Dst, Neglect>,
+        ///     Neglect: SafeTransmuteOptions,
+        /// {
+        ///     if Neglect::NEGLECTS_STABILITY {
+        ///         log::warn!("transmuting with {}", Neglect::DESCRIPTION);
+        ///     }
+        ///     src.transmute_into()
+        /// }
+        /// ```
+        #[rustc_on_unimplemented(
+            message = "`{Self}` is not a valid selection of transmute options",
+            note = "each option may be selected at most once, in the order `NeglectStability`, `NeglectAlignment`, `NeglectValidity`, `NeglectSize`, `NeglectProvenance`, and tuples of options may not be nested",
+        )]
+        pub trait UnsafeTransmuteOptions: private::Sealed
+        {
+            /// `true` if these options include [NeglectStability].
+            const NEGLECTS_STABILITY: bool = false;
+
+            /// `true` if these options include [NeglectAlignment].
+            const NEGLECTS_ALIGNMENT: bool = false;
+
+            /// `true` if these options include [NeglectValidity].
+            const NEGLECTS_VALIDITY: bool = false;
+
+            /// `true` if these options include [NeglectSize].
+            const NEGLECTS_SIZE: bool = false;
+
+            /// `true` if these options include [NeglectProvenance].
+            const NEGLECTS_PROVENANCE: bool = false;
+
+            /// A human-readable description of these options.
+            const DESCRIPTION: &'static str;
+        }
+
+        impl SafeTransmuteOptions for () {}
+        impl UnsafeTransmuteOptions for () {
+            const DESCRIPTION: &'static str = "no neglected checks";
+        }
+
+        /// Neglect the static stability check.
+        ///
+        /// By default, [TransmuteFrom] and [TransmuteInto] require that the [layouts of the source and destination types are SemVer-stable][super::stability]. The [NeglectStability] option disables this requirement.
+        ///
+        /// Prior to the adoption of the [stability declaration traits][super::stability], crate authors documented the layout guarantees of their types with doc comments. The [TransmuteFrom] and [TransmuteInto] traits and methods may be used with these types by requesting that the stability check is neglected; for instance:
+        /// 
+        /// ```rust
+        /// fn serialize<W: Write>(val : LibraryType, dst: W) -> std::io::Result<()>
+        /// where
+        ///     LibraryType: TransmuteInto<[u8; size_of::<LibraryType>()], NeglectStability>
+        /// {
+        ///     ...
+        /// }
+        /// ```
+        /// 
+        /// Neglecting stability over-eagerly cannot cause unsoundness or unsafety. For this reason, it is the only transmutation option available on the safe methods `transmute_from` and `transmute_into`. However, neglecting stability over-eagerly may cause your code to cease compiling if the authors of the source and destination types make changes that affect their layout.
+        /// 
+        /// By using the `NeglectStability` option to transmute types you do not own, you are committing to ensure that your reliance on these types' layouts is consistent with their documented stability guarantees.
+        pub struct NeglectStability;
+
+        // Uncomment this if/when constructibility is fully implemented:
+        impl SafeTransmuteOptions for NeglectStability {}
+        impl UnsafeTransmuteOptions for NeglectStability {
+            const NEGLECTS_STABILITY: bool = true;
+            const DESCRIPTION: &'static str = "NeglectStability";
+        }
+
+        /// Neglect the static alignment check.
+        ///
+        /// By default, [TransmuteFrom] and [TransmuteInto] are only implemented for references when the minimum alignment of the destination's referent type is no greater than the minimum alignment of the source's referent type. The `NeglectAlignment` option disables this requirement.
+        /// 
+        /// By using the `NeglectAlignment` option, you are committing to ensure that the transmuted reference satisfies the alignment requirements of the destination's referent type. For instance:
+        /// ```rust
+        /// /// Try to convert a `&T` into `&U`.
+        /// ///
+        /// /// This produces `None` if the referent isn't appropriately
+        /// /// aligned, as required by the destination type.
+        /// pub fn try_cast_ref<'t, 'u, T, U>(src: &'t T) -> Option<&'u U>
+        /// where
+        ///     &'t T: TransmuteInto<&'u U, NeglectAlignment>,
+        /// {
+        ///     if (src as *const T as usize) % align_of::<U>() != 0 {
+        ///         None
+        ///     } else {
+        ///         // Safe because we dynamically enforce the alignment
+        ///         // requirement, whose static check we chose to neglect.
+        ///         Some(unsafe { src.unsafe_transmute_into() })
+        ///     }
+        /// }
+        /// ```
+        pub struct NeglectAlignment;
+        impl UnsafeTransmuteOptions for NeglectAlignment {
+            const NEGLECTS_ALIGNMENT: bool = true;
+            const DESCRIPTION: &'static str = "NeglectAlignment";
+        }
+
+        /// Partially neglect the static validity check.
+        /// 
+        /// By default, [TransmuteFrom] and [TransmuteInto]'s methods require that all instantiations of the source type are guaranteed to be valid instantiations of the destination type. This precludes transmutations which *might* be valid depending on the source value:
+        /// ```rust
+        /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(u8)]
+        /// enum Bool {
+        ///     True = 1,
+        ///     False = 0,
+        /// }
+        /// 
+        /// /* ⚠️ This example intentionally does not compile. */
+        /// let _ : Bool  = some_u8_value.transmute_into(); // Compile Error!
+        /// ```
+        /// The [NeglectValidity] option disables this check.
+        /// 
+        /// By using the [NeglectValidity] option, you are committing to ensure dynamically source value is a valid instance of the destination type. For instance:
+        /// ```rust
+        /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(u8)]
+        /// enum Bool {
+        ///     True = 1,
+        ///     False = 0,
+        /// }
+        /// 
+        /// pub trait TryIntoBool
+        /// {
+        ///     fn try_into_bool(self) -> Option<Bool>;
+        /// }
+        /// 
+        /// impl<T> TryIntoBool for T
+        /// where
+        ///     T: TransmuteInto<u8>,
+        ///     u8: TransmuteInto<Bool, NeglectValidity>
+        /// {
+        ///     fn try_into_bool(self) -> Option<Bool> {
+        ///         let val: u8 = self.transmute_into();
+        /// 
+        ///         if val > 1 {
+        ///             None
+        ///         } else {
+        ///             // Safe, because we've first verified that
+        ///             // `val` is a bit-valid instance of a boolean.
+        ///             Some(unsafe {val.unsafe_transmute_into()})
+        ///         }
+        ///     }
+        /// }
+        /// ```
+        /// 
+        /// Even with [NeglectValidity], the compiler will still statically reject transmutations that cannot possibly be valid:
+        /// ```compile_fail
+        /// #[derive(PromiseTransmutableInto)]
+        /// #[repr(C)] enum Foo { A = 24 }
+        /// 
+        /// #[derive(PromiseTransmutableFrom)]
+        /// #[repr(C)] enum Bar { Z = 42 }
+        /// 
+        /// let _ = <Bar as TransmuteFrom<Foo, NeglectValidity>::unsafe_transmute_from(Foo::N) // Compile error!
+        /// ```
+        pub struct NeglectValidity;
+        impl UnsafeTransmuteOptions for NeglectValidity {
+            const NEGLECTS_VALIDITY: bool = true;
+            const DESCRIPTION: &'static str = "NeglectValidity";
+        }
+
+        /// Partially neglect the static size check.
+        ///
+        /// By default, [TransmuteFrom] and [TransmuteInto] forbid transmuting a value into a *larger* type, since the bytes of the destination beyond the end of the source would be uninitialized. The `NeglectSize` option permits these widening transmutations, provided that every byte of the destination's tail may be uninitialized; e.g., because it belongs to a [MaybeUninit][core::mem::MaybeUninit] field:
+        /// ```rust
+        /// #[derive(PromiseTransmutableFrom)]
+        /// #[repr(C)]
+        /// pub struct Reserved {
+        ///     pub header: Header,
+        ///     pub body: MaybeUninit<[u8; 1024]>,
+        /// }
+        ///
+        /// // The bytes of `body` are left uninitialized.
+        /// let buffer: Reserved = unsafe { header.unsafe_transmute_into() };
+        /// ```
+        /// This spares you from declaring a union of the source and destination types to reserve space.
+        ///
+        /// Even with [NeglectSize], the compiler will still statically reject widening transmutations whose destination tail must be initialized; e.g., from `u16` to `u32`. It only ever applies to transmutations of values, never to references, which must not refer to memory beyond the end of their source.
+        ///
+        /// By using the `NeglectSize` option, you are committing to ensure that the uninitialized tail of the destination is not assumed to be initialized by any safe API of the destination type; e.g., by initializing it before exposing the value.
+        pub struct NeglectSize;
+        impl UnsafeTransmuteOptions for NeglectSize {
+            const NEGLECTS_SIZE: bool = true;
+            const DESCRIPTION: &'static str = "NeglectSize";
+        }
+
+        /// Neglect the static provenance check.
+        ///
+        /// By default, [TransmuteFrom] and [TransmuteInto] forbid transmuting integers into pointers, and pointers into integers. Although their layouts are identical, a pointer carries *provenance*, the permission to access the memory it was derived from, which an integer does not. An integer transmuted into a pointer has no provenance, and dereferencing it is undefined behavior; a pointer transmuted into an integer may have its provenance silently discarded, invalidating the pointers later derived from that integer. The `NeglectProvenance` option permits these transmutations, which are common in FFI and intrusive data structures:
+        /// ```rust
+        /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(C)]
+        /// pub struct TaggedPtr { pub addr: usize }
+        ///
+        /// // The address of `node`, with its provenance exposed.
+        /// let tagged: TaggedPtr = unsafe { TransmuteFrom::<_, NeglectProvenance>::unsafe_transmute_from(node as *const Node) };
+        /// ```
+        ///
+        /// By using the `NeglectProvenance` option, you are committing to ensure that any pointer produced by the transmutation is only dereferenced if its address was obtained from a pointer to a live allocation, and that the target's memory model permits recovering that pointer's provenance from its address.
+        pub struct NeglectProvenance;
+        impl UnsafeTransmuteOptions for NeglectProvenance {
+            const NEGLECTS_PROVENANCE: bool = true;
+            const DESCRIPTION: &'static str = "NeglectProvenance";
+        }
+
+        /// (Extension) Options defined outside of this crate.
+        ///
+        /// [UnsafeTransmuteOptions] is sealed, but crates may experiment with new options by implementing this trait instead; e.g., a sanitizer crate might define:
+        /// ```rust
+        /// /// Neglect poisoning of uninitialized memory.
+        /// pub struct NeglectPoison;
+        ///
+        /// impl UnsafeTransmuteOptionsExtension for NeglectPoison {
+        ///     const DESCRIPTION: &'static str = "NeglectPoison";
+        /// }
+        /// ```
+        /// Every implementor of this trait implements [UnsafeTransmuteOptions], and may end a tuple of the options of this crate; e.g., `(NeglectAlignment, NeglectPoison)`.
+        ///
+        /// The compiler does not interpret extension options: they neglect no static checks, and are meaningful only to the third-party code that defines them. Since the compiler cannot vouch for their safety, they may only be used with *unsafe* transmutations.
+        #[unstable(feature = "transmute_options_extension", issue = "none")]
+        pub trait UnsafeTransmuteOptionsExtension {
+            /// A human-readable description of this option.
+            const DESCRIPTION: &'static str;
+        }
+
+        impl<T: UnsafeTransmuteOptionsExtension> UnsafeTransmuteOptions for T {
+            const DESCRIPTION: &'static str = T::DESCRIPTION;
+        }
+
+        /// Options that do not neglect the size check.
+        ///
+        /// Strictness options, like [RequireExactFit][crate::cast::options::RequireExactFit], require that the sizes of their source and destination fit exactly, and so may not be combined with [NeglectSize].
+        #[rustc_on_unimplemented(
+            message = "`{Self}` neglects the size check",
+            note = "strictness options, like `RequireExactFit`, may not be combined with `NeglectSize`",
+        )]
+        pub trait PreservesSize: UnsafeTransmuteOptions
+        {}
+
+        impl PreservesSize for () {}
+        impl PreservesSize for NeglectStability {}
+        impl PreservesSize for NeglectAlignment {}
+        impl PreservesSize for NeglectValidity {}
+        impl PreservesSize for NeglectProvenance {}
+        impl<T: UnsafeTransmuteOptionsExtension> PreservesSize for T {}
+
+        /// Implement the options traits for tuples of distinct options, listed in a canonical order.
+        ///
+        /// The tuples are generated recursively, as the ordered subsequences of the given options, so a selection like `(NeglectValidity, NeglectValidity)`, or one that lists its options out of order, is rejected at compile time. Since each selection has exactly one spelling, so does its `DESCRIPTION`. The constants of a tuple are the disjunction of its elements'. Tuples that exclude [NeglectSize] also implement [PreservesSize].
+        macro_rules! tuple_options {
+            ($($option:ident),+ $(,)?) => {
+                tuple_options!(@subsets preserves_size [] [$($option)+]);
+            };
+
+            // Each option is either included in, or excluded from, the tuple.
+            (@subsets $size:ident [$($chosen:ident)*] []) => {
+                tuple_options!(@impl $size $($chosen)*);
+            };
+            (@subsets $size:ident [$($chosen:ident)*] [NeglectSize $($rest:ident)*]) => {
+                tuple_options!(@subsets neglects_size [$($chosen)* NeglectSize] [$($rest)*]);
+                tuple_options!(@subsets $size [$($chosen)*] [$($rest)*]);
+            };
+            (@subsets $size:ident [$($chosen:ident)*] [$next:ident $($rest:ident)*]) => {
+                tuple_options!(@subsets $size [$($chosen)* $next] [$($rest)*]);
+                tuple_options!(@subsets $size [$($chosen)*] [$($rest)*]);
+            };
+
+            (@impl $size:ident) => {};
+            (@impl $size:ident $($option:ident)+) => {
+                tuple_options!(@size $size $($option)+);
+
+                impl private::Sealed for ($($option,)+) {}
+                impl UnsafeTransmuteOptions for ($($option,)+) {
+                    const NEGLECTS_STABILITY: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_STABILITY)+;
+                    const NEGLECTS_ALIGNMENT: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_ALIGNMENT)+;
+                    const NEGLECTS_VALIDITY: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_VALIDITY)+;
+                    const NEGLECTS_SIZE: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_SIZE)+;
+                    const NEGLECTS_PROVENANCE: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_PROVENANCE)+;
+                    const DESCRIPTION: &'static str = tuple_options!(@describe $($option)+);
+                }
+
+                // A tuple of built-in options may be suffixed by one extension option. Its
+                // description elides the extension option, whose meaning is known only to
+                // the crate defining it.
+                impl<E: UnsafeTransmuteOptionsExtension> private::Sealed for ($($option,)+ E) {}
+                impl<E: UnsafeTransmuteOptionsExtension> UnsafeTransmuteOptions for ($($option,)+ E) {
+                    const NEGLECTS_STABILITY: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_STABILITY)+;
+                    const NEGLECTS_ALIGNMENT: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_ALIGNMENT)+;
+                    const NEGLECTS_VALIDITY: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_VALIDITY)+;
+                    const NEGLECTS_SIZE: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_SIZE)+;
+                    const NEGLECTS_PROVENANCE: bool = false $(|| <$option as UnsafeTransmuteOptions>::NEGLECTS_PROVENANCE)+;
+                    const DESCRIPTION: &'static str = concat!("(", stringify!($($option),+), ", ..)");
+                }
+            };
+
+            // A one-element tuple keeps its trailing comma, as in `(NeglectAlignment,)`.
+            (@describe $option:ident) => { concat!("(", stringify!($option), ",)") };
+            (@describe $($option:ident)+) => { stringify!(($($option),+)) };
+
+            (@size neglects_size $($option:ident)+) => {};
+            (@size preserves_size $($option:ident)+) => {
+                impl PreservesSize for ($($option,)+) {}
+                impl<E: UnsafeTransmuteOptionsExtension> PreservesSize for ($($option,)+ E) {}
+            };
+        }
+
+        tuple_options!(NeglectStability, NeglectAlignment, NeglectValidity, NeglectSize, NeglectProvenance);
+
+        impl SafeTransmuteOptions for (NeglectStability,) {}
+
+        // prevent third-party implementations of `UnsafeTransmuteOptions`
+        mod private {
+            use super::*;
+
+            pub trait Sealed {}
+
+            impl Sealed for () {}
+            impl Sealed for NeglectStability {}
+            impl Sealed for NeglectAlignment {}
+            impl Sealed for NeglectValidity {}
+            impl Sealed for NeglectSize {}
+            impl Sealed for NeglectProvenance {}
+            impl<T: UnsafeTransmuteOptionsExtension> Sealed for T {}
+        }
+    }
+
+}
+
+/// (Extension) Traits for querying layout properties.
+///
+/// The definition of these traits demonstrate how [TransmuteFrom] can be used to query properties of a type's layout.
+/// See the [`Vec` casting demonstration][cast::CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] for an example of their use.
+#[unstable(feature = "cast", issue = "none")]
+pub mod mem {
+    use crate::transmute::{TransmuteFrom, stability::*, options::*};
+
+    /// Implemented if `align_of::<Self>() <= align_of::<Rhs>()`
+    pub trait AlignLtEq<Rhs, Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// By wrapping a type in a zero-sized array, we neutralize its validity and size qualities. The only quality by which `[Lhs; 0]` and `[Dst; 0]` can differ is their alignment. We check *only* if the alignment of `Lhs` is less than `Rhs` by transmuting between references of these zero-sized gadgets.
+    impl<Lhs, Rhs, Neglect> AlignLtEq<Rhs, Neglect> for Lhs
+    where
+        Neglect: UnsafeTransmuteOptions,
+        for<'a> &'a [Lhs; 0]: TransmuteFrom<&'a [Rhs; 0], Neglect>
+    {}
+
+    /// Implemented if `align_of::<Self>() == align_of::<Rhs>()`
+    pub trait AlignEq<Rhs, Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// See [AlignLtEq].
+    impl<Lhs, Rhs, Neglect> AlignEq<Rhs, Neglect> for Lhs
+    where
+        Neglect: UnsafeTransmuteOptions,
+        Lhs: AlignLtEq<Rhs>,
+        Rhs: AlignLtEq<Lhs>,
+    {}
+
+    /// A zero-sized type with the greatest fundamental alignment of the target; i.e., the alignment of C's `max_align_t`.
+    ///
+    /// Every scalar type is no more aligned than `MaxAlignT`. Unlike C's `max_align_t`, this type is zero-sized, so it may be used as a leading alignment marker; e.g., `([MaxAlignT; 0], [u8; 4096])`.
+    #[cfg_attr(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64", target_arch = "powerpc64", target_arch = "riscv64"), repr(C, align(16)))]
+    #[cfg_attr(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64", target_arch = "powerpc64", target_arch = "riscv64")), repr(C, align(8)))]
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct MaxAlignT {
+        _private: [u8; 0],
+    }
+
+    impl PromiseTransmutableInto for MaxAlignT { type Archetype = Self; }
+    impl PromiseTransmutableFrom for MaxAlignT { type Archetype = Self; }
+
+    /// Implemented if `align_of::<Self>() <= align_of::<MaxAlignT>()`; i.e., if `Self` is fundamentally aligned.
+    pub trait MaxAlign<Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// See [AlignLtEq].
+    impl<T, Neglect> MaxAlign<Neglect> for T
+    where
+        Neglect: UnsafeTransmuteOptions,
+        T: AlignLtEq<MaxAlignT>,
+    {}
+
+    /// Implemented if `align_of::<Self>() >= align_of::<T>()`; i.e., if every `Self` is suitably aligned to back a `T`.
+    ///
+    /// By default, `T` is [MaxAlignT], so that `Buf: OverAligned` holds if `Buf` may back any fundamentally aligned type; e.g.:
+    /// ```rust
+    /// fn arena<Buf: OverAligned>(buf: &mut Buf) -> Arena<'_> {
+    ///     ...
+    /// }
+    /// ```
+    pub trait OverAligned<T=MaxAlignT, Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// See [AlignLtEq].
+    impl<Buf, T, Neglect> OverAligned<T, Neglect> for Buf
+    where
+        Neglect: UnsafeTransmuteOptions,
+        T: AlignLtEq<Buf>,
+    {}
+
+    use core::mem::MaybeUninit;
+
+    // The alignment of this struct is always equal to `max(align_of::<A>(), align_of::<T>())`.
+    // Its validity is always equal to `MaybeUninit<T>`.
+    // Its size equals `T`
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[repr(C)]
+    struct Aligned<A, T>(pub [A; 0], pub MaybeUninit<T>);
+
+    /// Implemented if `size_of::<Self>() <= size_of::<Rhs>()`
+    pub trait SizeLtEq<Rhs, Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// We wrap the types in a struct that neutralizes their alignment and validity differences, leaving size as the only quality that might differ between `Aligned<Rhs, Lhs>` and `Aligned<Lhs, Rhs>`.
+    impl<Lhs, Rhs, Neglect> SizeLtEq<Rhs, Neglect> for Lhs
+    where
+        Neglect: UnsafeTransmuteOptions,
+        for<'a> &'a Aligned<Rhs, Lhs>: TransmuteFrom<&'a Aligned<Lhs, Rhs>>,
+    {}
+
+    /// Implemented if `size_of::<Self>() == size_of::<Rhs>()`
+    pub trait SizeEq<Rhs, Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// See [SizeLtEq].
+    impl<Lhs, Rhs, Neglect> SizeEq<Rhs, Neglect> for Lhs
+    where
+        Neglect: UnsafeTransmuteOptions,
+        Lhs: SizeLtEq<Rhs>,
+        Rhs: SizeLtEq<Lhs>,
+    {}
+
+    /// Implemented if `size_of::<Self>() > 0`
+    pub trait NonZeroSized<Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// A type is non-zero-sized if it is no smaller than `u8`. See [SizeLtEq].
+    impl<T, Neglect> NonZeroSized<Neglect> for T
+    where
+        Neglect: UnsafeTransmuteOptions,
+        u8: SizeLtEq<T>,
+    {}
+
+    use core::mem::size_of;
+
+    /// Types whose size is exposed as an associated constant.
+    ///
+    /// This trait is implemented for all sized types. `T::SIZE` may be used in array lengths and static assertions in const-generic code; e.g.:
+    /// ```rust
+    /// struct Slab<T: SizeOf, const N: usize> {
+    ///     bytes: [u8; T::SIZE * N],
+    /// }
+    /// ```
+    /// If `A: SizeEq<B>`, then `A::SIZE == B::SIZE`.
+    pub trait SizeOf {
+        /// `size_of::<Self>()`
+        const SIZE: usize;
+    }
+
+    impl<T> SizeOf for T {
+        const SIZE: usize = size_of::<T>();
+    }
+
+    /// Types whose alignment is exposed as an associated constant.
+    ///
+    /// This trait is implemented for all sized types. If `A: AlignLtEq<B>`, then `A::ALIGN <= B::ALIGN`.
+    pub trait AlignOf {
+        /// `align_of::<Self>()`
+        const ALIGN: usize;
+    }
+
+    impl<T> AlignOf for T {
+        const ALIGN: usize = core::mem::align_of::<T>();
+    }
+
+    /// The bytes of a `T`: `[u8; size_of::<T>()]`.
+    ///
+    /// Bounds relating a type to its bytes may use this alias, rather than repeating the array type; e.g.:
+    /// ```rust
+    /// fn serialize<T>(val: T) -> Bytes<T>
+    /// where
+    ///     Bytes<T>: TransmuteFrom<T>,
+    /// {
+    ///     val.transmute_into()
+    /// }
+    /// ```
+    pub type Bytes<T> = [u8; size_of::<T>()];
+
+    /// The possibly-uninitialized bytes of a `T`: `[MaybeUninit<u8>; size_of::<T>()]`.
+    ///
+    /// Every `T` is transmutable into its `UninitBytes`, including its padding bytes.
+    pub type UninitBytes<T> = [MaybeUninit<u8>; size_of::<T>()];
+
+    /// Implemented if every initialized bit pattern of `size_of::<Self>()` bytes is a valid instance of `Self`.
+    ///
+    /// This names the bound that parsing APIs would otherwise spell out in full; e.g.:
+    /// ```rust
+    /// fn parse<T: FromAnyBytes>(bytes: &[u8]) -> Option<&T> {
+    ///     ...
+    /// }
+    /// ```
+    pub trait FromAnyBytes<Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// A type is transmutable from any bytes if it is transmutable from a byte array of its size.
+    impl<T, Neglect> FromAnyBytes<Neglect> for T
+    where
+        Neglect: UnsafeTransmuteOptions,
+        T: TransmuteFrom<Bytes<T>, Neglect>,
+    {}
+
+    /// Implemented if `Self` contains no padding or otherwise uninitialized bytes, and so may be viewed as a `[u8]`.
+    ///
+    /// The complement of [FromAnyBytes]; e.g.:
+    /// ```rust
+    /// fn checksum<T: NoUninit>(val: &T) -> u32 {
+    ///     ...
+    /// }
+    /// ```
+    pub trait NoUninit<Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// A type has no uninitialized bytes if it is transmutable into a byte array of its size.
+    impl<T, Neglect> NoUninit<Neglect> for T
+    where
+        Neglect: UnsafeTransmuteOptions,
+        Bytes<T>: TransmuteFrom<T, Neglect>,
+    {}
+
+    /// Implemented if `Self` has exactly the layout of the C struct `C`, which is usually declared with [c_struct!].
+    ///
+    /// Bindings crates may use this gadget as a static firewall before transmuting across an FFI boundary: if the layout of a Rust type diverges from the C declaration it mirrors, compilation fails; e.g.:
+    /// ```rust
+    /// c_struct! {
+    ///     /// `struct timespec` from `<time.h>`.
+    ///     pub struct timespec_c { tv_sec: c_long, tv_nsec: c_long }
+    /// }
+    ///
+    /// const _: () = {
+    ///     fn assert_compatible() where Timespec: CReprCompatible<timespec_c> {}
+    /// };
+    /// ```
+    pub trait CReprCompatible<C, Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// `Self` and `C` have the same size and validity if each is transmutable from the other, and references to them are interchangeable if their alignments are also equal.
+    impl<T, C, Neglect> CReprCompatible<C, Neglect> for T
+    where
+        Neglect: UnsafeTransmuteOptions,
+        T: TransmuteFrom<C, Neglect> + AlignEq<C, Neglect>,
+        C: TransmuteFrom<T, Neglect>,
+    {}
+
+    /// Implemented if `Self` and `Rhs` are each transmutable into the other.
+    ///
+    /// This names the bound that in-place round-trips would otherwise spell out as two clauses; e.g.:
+    /// ```rust
+    /// fn round_trip<A, B>(a: A, f: impl FnOnce(B) -> B) -> A
+    /// where
+    ///     A: Transmutable<B>,
+    /// {
+    ///     f(a.transmute_into()).transmute_into()
+    /// }
+    /// ```
+    pub trait Transmutable<Rhs, Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// See [CReprCompatible], which additionally requires equal alignments.
+    impl<Lhs, Rhs, Neglect> Transmutable<Rhs, Neglect> for Lhs
+    where
+        Neglect: UnsafeTransmuteOptions,
+        Lhs: TransmuteFrom<Rhs, Neglect>,
+        Rhs: TransmuteFrom<Lhs, Neglect>,
+    {}
+
+    /// Declare a `#[repr(C)]` struct mirroring a C declaration, for use with [CReprCompatible].
+    ///
+    /// The fields are listed in the same order, and with the same types, as in C. The struct promises that its layout is stable, since it is fixed by the C declaration.
+    pub macro c_struct {
+        ($(#[$attr:meta])* $vis:vis struct $name:ident { $($field:ident : $ty:ty),* $(,)? }) => {
+            $(#[$attr])*
+            #[repr(C)]
+            #[allow(non_camel_case_types)]
+            $vis struct $name { $(pub $field: $ty),* }
+
+            impl $crate::transmute::stability::PromiseTransmutableInto for $name { type Archetype = Self; }
+            impl $crate::transmute::stability::PromiseTransmutableFrom for $name { type Archetype = Self; }
+        }
+    }
+
+    // The layout of this struct is `Prefix`, followed by `Tail`.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[repr(C)]
+    struct Spliced<Prefix, Tail>(pub Prefix, pub Tail);
+
+    /// Implemented if `Self` is laid out as a `Prefix` followed by a `Tail`.
+    pub trait HasPrefix<Prefix, Tail, Neglect=()>
+    where
+        Neglect: UnsafeTransmuteOptions,
+    {}
+
+    /// Since `Whole` and `Spliced<Prefix, Tail>` are transmutable in *both* directions, the leading bytes of every `Whole` are a valid `Prefix`, and overwriting them with *any* `Prefix` produces a valid `Whole`.
+    impl<Whole, Prefix, Tail, Neglect> HasPrefix<Prefix, Tail, Neglect> for Whole
+    where
+        Neglect: UnsafeTransmuteOptions,
+        Whole: TransmuteFrom<Spliced<Prefix, Tail>, Neglect>,
+        Spliced<Prefix, Tail>: TransmuteFrom<Whole, Neglect>,
+    {}
+
+    /// Project a mutable reference to a struct under construction into a mutable reference to one of its fields.
+    ///
+    /// A `&mut MaybeUninit<Outer>` is projected into a `&mut MaybeUninit<Field>`, without ever creating a reference to uninitialized memory. (Initialized values need no macro; `&mut outer.field` already projects them.) The type of the struct must be named, so that fields reached through [Deref][core::ops::Deref] are rejected at compile time; nested paths are projected by nesting invocations:
+    /// ```rust
+    /// let mut packet = MaybeUninit::<Packet>::uninit();
+    /// project!(uninit &mut packet, Packet => .body).write([0; 1024]);
+    /// let header = project!(uninit &mut packet, Packet => .header);
+    /// project!(uninit header, Header => .len).write(1024);
+    /// ```
+    /// Fields of `#[repr(packed)]` structs may be misaligned; projecting into one panics.
+    pub macro project {
+        (uninit $outer:expr, $ty:path => .$field:tt) => {{
+            let outer: &mut core::mem::MaybeUninit<$ty> = $outer;
+            // Fails to compile if `$field` is not a field of `$ty` itself.
+            #[allow(unreachable_code)]
+            if false { let $ty { $field: _, .. } = loop {}; }
+            let ptr = outer.as_mut_ptr();
+            // Safe because `ptr` is derived from a reference to a `$ty`, so
+            // the projected place is in bounds, and `&raw` creates no
+            // intermediate reference to possibly uninitialized memory.
+            unsafe { project_uninit(outer, &raw mut (*ptr).$field) }
+        }},
+    }
+
+    /// Split a mutable reference to a struct into simultaneous mutable references to several of its fields.
+    ///
+    /// Each field is borrowed as by `&mut outer.path`, so in-place codecs may mutate the header and payload of the same record at once:
+    /// ```rust
+    /// let (header, body) = split_fields!(&mut packet, .header, .body);
+    /// header.len = encode(body)?;
+    /// ```
+    /// The fields must occupy disjoint byte ranges. This is checked at compile time: paths that overlap, such as `.header` and `.header.len`, and fields of unions, are rejected by the borrow checker, as are several fields reached through the same [DerefMut][core::ops::DerefMut] implementation.
+    pub macro split_fields($outer:expr $(, $(.$field:tt)+)+ $(,)?) {{
+        let outer: &mut _ = $outer;
+        ($(&mut outer$(.$field)+,)+)
+    }}
+
+    /// Produce a `&mut MaybeUninit<F>` to the field `field` of `outer`. Used by [project!].
+    ///
+    /// # Safety
+    /// `field` must point to a field of `outer`.
+    #[inline(always)]
+    unsafe fn project_uninit<'a, O, F>(_outer: &'a mut MaybeUninit<O>, field: *mut F) -> &'a mut MaybeUninit<F> {
+        assert!(runtime::is_aligned_to::<F>(field.cast()), "projected field is misaligned");
+        // Safe because `field` is an aligned pointer into `outer`, which is
+        // mutably borrowed for `'a`.
+        &mut *field.cast::<MaybeUninit<F>>()
+    }
+
+    /// Runtime alignment utilities.
+    ///
+    /// Where alignment cannot be checked statically (e.g., with [NeglectAlignment]), it must be checked at runtime. These are the checks performed by this crate's dynamically-checked casts, exposed for callers implementing their own fallbacks; e.g.:
+    /// ```rust
+    /// let (head, body) = split_aligned::<u64>(bytes);
+    /// // Safe because `body` is aligned for `u64`.
+    /// let words: &[u64] = unsafe { CastFrom::<_, NeglectAlignment>::unsafe_cast_from(body) };
+    /// head.iter().for_each(process_byte);
+    /// ```
+    pub mod runtime {
+        use core::mem::align_of;
+
+        /// Produces `true` if `ptr` is suitably aligned for a `T`.
+        #[inline(always)]
+        pub fn is_aligned_to<T>(ptr: *const u8) -> bool {
+            ptr.align_offset(align_of::<T>()) == 0
+        }
+
+        /// The number of leading bytes of `bytes` that must be skipped to reach an address suitably aligned for a `T`.
+        ///
+        /// Produces `None` if `bytes` contains no such address.
+        #[inline]
+        pub fn align_offset_for<T>(bytes: &[u8]) -> Option<usize> {
+            // `align_offset` produces `usize::MAX` if no aligned address is reachable.
+            let offset = bytes.as_ptr().align_offset(align_of::<T>());
+            match offset <= bytes.len() {
+                true => Some(offset),
+                false => None,
+            }
+        }
+
+        /// Split `bytes` at the first address suitably aligned for a `T`.
+        ///
+        /// If `bytes` contains no such address, the second slice is empty.
+        #[inline]
+        pub fn split_aligned<T>(bytes: &[u8]) -> (&[u8], &[u8]) {
+            bytes.split_at(align_offset_for::<T>(bytes).unwrap_or(bytes.len()))
+        }
+    }
+
+    /// Enums with a primitive representation; e.g., `#[repr(u8)]`.
+    ///
+    /// `Repr` is the integer type named in the enum's `repr` attribute:
+    /// ```rust
+    /// #[derive(PromiseTransmutableInto)]
+    /// #[repr(u8)]
+    /// enum State { Idle = 0, Busy = 1, Done = 2 }
+    ///
+    /// impl PrimitiveRepr for State { type Repr = u8; }
+    /// ```
+    ///
+    /// `#[derive(PromiseTransmutableFrom)]` generates this implementation for field-less enums. Implementing this trait by hand is safe: an incorrect `Repr` will merely fail the transmutability bounds of [discriminant_bits].
+    pub trait PrimitiveRepr {
+        /// The primitive representation of `Self`'s discriminant.
+        type Repr: Copy;
+    }
+
+    /// Read the discriminant of `e` as its primitive representation.
+    ///
+    /// Unlike an `as` cast, this is rejected for enums that are not `PromiseTransmutableInto` or whose discriminant is not a leading `E::Repr`. For enums with fields, only the tag is read.
+    #[inline(always)]
+    pub fn discriminant_bits<E>(e: &E) -> E::Repr
+    where
+        E: PrimitiveRepr,
+        for<'a> &'a E::Repr: TransmuteFrom<&'a E>,
+    {
+        *<&E::Repr as TransmuteFrom<&E>>::transmute_from(e)
+    }
+
+    /// Read the discriminants of `es` as their primitive representation.
+    ///
+    /// This is only available for field-less enums, whose size equals that of `E::Repr`.
+    #[inline(always)]
+    pub fn discriminant_bits_slice<E>(es: &[E]) -> &[E::Repr]
+    where
+        E: PrimitiveRepr + SizeEq<E::Repr>,
+        for<'a> &'a [E::Repr]: crate::cast::CastFrom<&'a [E]>,
+    {
+        crate::cast::CastFrom::cast_from(es)
+    }
+}
+
+/// (Extension) Bit-altering conversions.
+///
+/// This module demonstrates how the [transmute] API may be used (with a future iteration of const generics) to permit sound and complete slice casting.
+///
+/// For more information on this extension, [**see here**](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#case-study-abstractions-for-fast-parsing).
+#[unstable(feature = "cast", issue = "none")]
+pub mod cast {
+
+    use options::*;
+    use crate::transmute::TransmuteFrom;
+
+    /// Cast `Self` into `Dst`.
+    ///
+    /// The reciprocal of [CastFrom]. This trait is implemented in terms of [CastFrom].
+    pub trait CastInto<Dst, Neglect=()>
+    where
+        Dst: CastFrom<Self, Neglect>,
+        Neglect: UnsafeCastOptions,
+    {
+        /// Cast `self` into a value of type `Dst`, safely.
+        #[inline(always)]
+        fn cast_into(self) -> Dst
+        where
+            Self: Sized,
+            Dst: Sized,
+            Neglect: SafeCastOptions,
+        {
+            CastFrom::<_, Neglect>::cast_from(self)
+        }
+
+        /// Cast `self` into a value of type `Dst`, potentially unsafely.
+        #[inline(always)]
+        unsafe fn unsafe_cast_into(self) -> Dst
+        where
+            Self: Sized,
+            Dst: Sized,
+            Neglect: UnsafeCastOptions,
+        {
+            CastFrom::<_, Neglect>::unsafe_cast_from(self)
+        }
+    }
+
+    impl<Src, Dst, Neglect> CastInto<Dst, Neglect> for Src
+    where
+        Dst: CastFrom<Self, Neglect>,
+        Neglect: UnsafeCastOptions,
+    {}
+
+    /// Instantiate `Self` from a value of type `Src`.
+    ///
+    /// The reciprocal of [CastInto].
+    pub trait CastFrom<Src: ?Sized, Neglect=()>
+    where
+        Neglect: UnsafeCastOptions,
+    {
+        /// Instantiate `Self` by casting a value of type `Src`, safely.
+        #[inline(always)]
+        fn cast_from(src: Src) -> Self
+        where
+            Src: Sized,
+            Self: Sized,
+            Neglect: SafeCastOptions
+        {
+            unsafe { CastFrom::<_,Neglect>::unsafe_cast_from(src) }
+        }
+
+        /// Instantiate `Self` by casting a value of type `Src`, potentially safely.
+        unsafe fn unsafe_cast_from(src: Src) -> Self
+        where
+            Src: Sized,
+            Self: Sized,
+            Neglect: UnsafeCastOptions;
+    }
+
+    /// Cast a reference to `Self` into a reference to `Dst`.
+    ///
+    /// Bounds on [CastFrom] for borrowed casts must be higher-ranked over the lifetime of the borrow; e.g.:
+    /// ```rust
+    /// fn checksum<T: ?Sized>(data: &T) -> u32
+    /// where
+    ///     for<'a> &'a [u8]: CastFrom<&'a T>,
+    /// {
+    ///     ...
+    /// }
+    /// ```
+    /// This trait is implemented for all such `Self` and `Dst`, abbreviating the above bound to:
+    /// ```rust
+    /// fn checksum<T: ?Sized + CastRef<[u8]>>(data: &T) -> u32
+    /// {
+    ///     let bytes: &[u8] = data.cast_ref();
+    ///     ...
+    /// }
+    /// ```
+    pub trait CastRef<Dst: ?Sized, Neglect=()>
+    where
+        Neglect: UnsafeCastOptions,
+    {
+        /// Cast `&self` into a reference of type `&Dst`, safely.
+        fn cast_ref(&self) -> &Dst
+        where
+            Neglect: SafeCastOptions;
+
+        /// Cast `&self` into a reference of type `&Dst`, potentially unsafely.
+        unsafe fn unsafe_cast_ref(&self) -> &Dst
+        where
+            Neglect: UnsafeCastOptions;
+    }
+
+    impl<Src, Dst, Neglect> CastRef<Dst, Neglect> for Src
+    where
+        Src: ?Sized,
+        Dst: ?Sized,
+        Neglect: UnsafeCastOptions,
+        for<'a> &'a Dst: CastFrom<&'a Src, Neglect>,
+    {
+        #[inline(always)]
+        fn cast_ref(&self) -> &Dst
+        where
+            Neglect: SafeCastOptions,
+        {
+            CastFrom::<_, Neglect>::cast_from(self)
+        }
+
+        #[inline(always)]
+        unsafe fn unsafe_cast_ref(&self) -> &Dst
+        where
+            Neglect: UnsafeCastOptions,
+        {
+            CastFrom::<_, Neglect>::unsafe_cast_from(self)
+        }
+    }
+
+    /// Cast a mutable reference to `Self` into a mutable reference to `Dst`.
+    ///
+    /// The mutable counterpart of [CastRef].
+    pub trait CastMut<Dst: ?Sized, Neglect=()>
+    where
+        Neglect: UnsafeCastOptions,
+    {
+        /// Cast `&mut self` into a reference of type `&mut Dst`, safely.
+        fn cast_mut(&mut self) -> &mut Dst
+        where
+            Neglect: SafeCastOptions;
+
+        /// Cast `&mut self` into a reference of type `&mut Dst`, potentially unsafely.
+        unsafe fn unsafe_cast_mut(&mut self) -> &mut Dst
+        where
+            Neglect: UnsafeCastOptions;
+    }
+
+    impl<Src, Dst, Neglect> CastMut<Dst, Neglect> for Src
+    where
+        Src: ?Sized,
+        Dst: ?Sized,
+        Neglect: UnsafeCastOptions,
+        for<'a> &'a mut Dst: CastFrom<&'a mut Src, Neglect>,
+    {
+        #[inline(always)]
+        fn cast_mut(&mut self) -> &mut Dst
+        where
+            Neglect: SafeCastOptions,
+        {
+            CastFrom::<_, Neglect>::cast_from(self)
+        }
+
+        #[inline(always)]
+        unsafe fn unsafe_cast_mut(&mut self) -> &mut Dst
+        where
+            Neglect: UnsafeCastOptions,
+        {
+            CastFrom::<_, Neglect>::unsafe_cast_from(self)
+        }
+    }
+
+    /// An extension point for containers whose elements may be cast in place.
+    ///
+    /// Implementing this trait for `C<Src>` with the target `C<Dst>`, and then invoking [impl_container_cast!] for the pair, implements [CastFrom] (and hence [CastInto]) with the same bounds as the standard library's containers:
+    ///  - `Dst` must be transmutable from `Src`, with the selected options, and
+    ///  - `Dst` and `Src` must have equal size ([SizeEq][crate::mem::SizeEq]) and alignment ([AlignEq][crate::mem::AlignEq]), so that the container's storage may be reused without reallocating.
+    ///
+    /// For instance, a crate providing a fixed-capacity vector might implement:
+    /// ```rust
+    /// unsafe impl<Src, Dst, const N: usize> ContainerCast<ArrayVec<Dst, N>> for ArrayVec<Src, N> {
+    ///     type SrcElement = Src;
+    ///     type DstElement = Dst;
+    ///
+    ///     unsafe fn cast_elements(self) -> ArrayVec<Dst, N> {
+    ///         let (buf, len) = self.into_raw_parts();
+    ///         ArrayVec::from_raw_parts(buf.cast(), len)
+    ///     }
+    /// }
+    /// ```
+    /// ...and then:
+    /// ```rust
+    /// impl_container_cast!([Src, Dst, const N: usize] ArrayVec<Src, N> => ArrayVec<Dst, N>);
+    /// ```
+    /// ...with which `ArrayVec<[u8; 4], N>` may be cast into `ArrayVec<u32, N>`.
+    ///
+    /// # Safety
+    /// `Self` and `Dst` must be the same container, differing only in their element types, `SrcElement` and `DstElement`. Provided that `DstElement` is transmutable from `SrcElement`, and is of equal size and alignment, [cast_elements][ContainerCast::cast_elements] must produce a `Dst` that owns exactly the storage and elements of `self`.
+    pub unsafe trait ContainerCast<Dst>: Sized {
+        /// The element type of `Self`.
+        type SrcElement;
+
+        /// The element type of `Dst`.
+        type DstElement;
+
+        /// Reinterpret the elements of `self` as `DstElement`, reusing its storage.
+        ///
+        /// This method is called by [CastFrom::unsafe_cast_from], which statically ensures that `DstElement` is transmutable from `SrcElement`, and of equal size and alignment.
+        unsafe fn cast_elements(self) -> Dst;
+    }
+
+    /// Implement [CastFrom] for a container implementing [ContainerCast].
+    ///
+    /// The generic parameters of the impl are listed in brackets, followed by the source and destination containers; e.g.:
+    /// ```rust
+    /// impl_container_cast!([Src, Dst, const N: usize] SmallVec<[Src; N]> => SmallVec<[Dst; N]>);
+    /// ```
+    /// The requirements of reusing the container's storage are enforced statically with the [AlignEq][crate::mem::AlignEq] and [SizeEq][crate::mem::SizeEq] gadgets.
+    ///
+    /// A blanket implementation of [CastFrom] for every [ContainerCast] would overlap with every other implementation of [CastFrom], so each container is opted in with a concrete implementation.
+    pub macro impl_container_cast([$($generics:tt)*] $src:ty => $dst:ty) {
+        impl<$($generics)*, Neglect> $crate::cast::CastFrom<$src, Neglect> for $dst
+        where
+            Neglect: $crate::cast::options::UnsafeContainerCastOptions,
+            $src: $crate::cast::ContainerCast<$dst>,
+            <$src as $crate::cast::ContainerCast<$dst>>::DstElement:
+                $crate::transmute::TransmuteFrom<<$src as $crate::cast::ContainerCast<$dst>>::SrcElement, Neglect>
+                + $crate::mem::AlignEq<<$src as $crate::cast::ContainerCast<$dst>>::SrcElement, Neglect>
+                + $crate::mem::SizeEq<<$src as $crate::cast::ContainerCast<$dst>>::SrcElement, Neglect>,
+        {
+            #[doc(hidden)]
+            #[inline(always)]
+            unsafe fn unsafe_cast_from(src: $src) -> $dst
+            where
+                Neglect: $crate::cast::options::UnsafeContainerCastOptions,
+            {
+                $crate::cast::ContainerCast::cast_elements(src)
+            }
+        }
+    }
+
+    use core::slice;
+
+    /// Group a byte slice into `K`-byte chunks, returning the ungrouped remainder.
+    ///
+    /// This is a safe alternative to `chunks_exact` followed by `try_into` for block-oriented APIs, such as ciphers and hash functions:
+    /// ```rust
+    /// let (blocks, tail): (&[[u8; 16]], &[u8]) = group_bytes(message);
+    /// for block in blocks {
+    ///     cipher.encrypt_block(block);
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn group_bytes<const K: usize>(bytes: &[u8]) -> (&[[u8; K]], &[u8])
+    {
+        let len = bytes.len().checked_div(K).unwrap_or(0);
+        let (body, tail) = bytes.split_at(len * K);
+        // Safe because `[u8; K]` has the alignment of `u8`, and `body` is exactly `len * K` bytes long.
+        (unsafe { slice::from_raw_parts(body.as_ptr().cast::<[u8; K]>(), len) }, tail)
+    }
+
+    /// Group a mutable byte slice into `K`-byte chunks, returning the ungrouped remainder.
+    ///
+    /// The mutable counterpart of [group_bytes].
+    #[inline(always)]
+    pub fn group_bytes_mut<const K: usize>(bytes: &mut [u8]) -> (&mut [[u8; K]], &mut [u8])
+    {
+        let len = bytes.len().checked_div(K).unwrap_or(0);
+        let (body, tail) = bytes.split_at_mut(len * K);
+        // Safe because `[u8; K]` has the alignment of `u8`, and `body` is exactly `len * K` bytes long.
+        (unsafe { slice::from_raw_parts_mut(body.as_mut_ptr().cast::<[u8; K]>(), len) }, tail)
+    }
+
+    /// Group a byte slice into `K`-byte chunks, or `None` if its length is not a multiple of `K`.
+    ///
+    /// Unlike [group_bytes], a remainder is rejected rather than returned.
+    #[inline(always)]
+    pub fn try_group_bytes<const K: usize>(bytes: &[u8]) -> Option<&[[u8; K]]>
+    {
+        try_unflatten_slice(bytes)
+    }
+
+    /// Flatten a slice of `K`-byte chunks back into a byte slice.
+    ///
+    /// The reciprocal of [group_bytes].
+    #[inline(always)]
+    pub fn ungroup_bytes<const K: usize>(groups: &[[u8; K]]) -> &[u8]
+    {
+        flatten_slice(groups)
+    }
+
+    /// Flatten a slice of arrays `&[[T; N]]` into a slice of their elements `&[T]`.
+    ///
+    /// Arrays are laid out without padding between their elements, so this conversion always succeeds:
+    /// ```rust
+    /// let frames: &[[f32; 2]] = &[[0.0, 1.0], [2.0, 3.0]];
+    /// let samples: &[f32] = flatten_slice(frames);
+    /// assert_eq!(samples, &[0.0, 1.0, 2.0, 3.0]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the flattened length overflows `usize`, which is only possible if `T` is zero-sized.
+    #[inline(always)]
+    pub fn flatten_slice<T, const N: usize>(src: &[[T; N]]) -> &[T]
+    {
+        // The product only overflows if `T` is zero-sized.
+        let len = src.len().checked_mul(N).expect("flattened slice length overflows `usize`");
+        // Safe because `[T; N]` is exactly `N` consecutive `T`s, with the alignment of `T`.
+        unsafe { slice::from_raw_parts(src.as_ptr().cast::<T>(), len) }
+    }
+
+    /// Flatten a slice of arrays `&mut [[T; N]]` into a slice of their elements `&mut [T]`.
+    ///
+    /// The mutable counterpart of [flatten_slice].
+    #[inline(always)]
+    pub fn flatten_slice_mut<T, const N: usize>(src: &mut [[T; N]]) -> &mut [T]
+    {
+        // The product only overflows if `T` is zero-sized.
+        let len = src.len().checked_mul(N).expect("flattened slice length overflows `usize`");
+        // Safe because `[T; N]` is exactly `N` consecutive `T`s, with the alignment of `T`.
+        unsafe { slice::from_raw_parts_mut(src.as_mut_ptr().cast::<T>(), len) }
+    }
+
+    /// Group a slice `&[T]` into a slice of arrays `&[[T; N]]`.
+    ///
+    /// The reciprocal of [flatten_slice]. This produces `None` if the length of `src` is not a multiple of `N`, or if `N` is zero.
+    #[inline(always)]
+    pub fn try_unflatten_slice<T, const N: usize>(src: &[T]) -> Option<&[[T; N]]>
+    {
+        match src.len().checked_rem(N) {
+            // Safe because `src` consists of exactly `src.len() / N` runs of `N` consecutive `T`s.
+            Some(0) => Some(unsafe { slice::from_raw_parts(src.as_ptr().cast::<[T; N]>(), src.len() / N) }),
+            _ => None,
+        }
+    }
+
+    /// Group a slice `&mut [T]` into a slice of arrays `&mut [[T; N]]`.
+    ///
+    /// The mutable counterpart of [try_unflatten_slice].
+    #[inline(always)]
+    pub fn try_unflatten_slice_mut<T, const N: usize>(src: &mut [T]) -> Option<&mut [[T; N]]>
+    {
+        match src.len().checked_rem(N) {
+            // Safe because `src` consists of exactly `src.len() / N` runs of `N` consecutive `T`s.
+            Some(0) => Some(unsafe { slice::from_raw_parts_mut(src.as_mut_ptr().cast::<[T; N]>(), src.len() / N) }),
+            _ => None,
+        }
+    }
+
+    use crate::transmute::options::NeglectAlignment;
+
+    /// Cast the maximal aligned middle of `&[Src]` into `&[Dst]`, returning the unconverted head and tail.
+    ///
+    /// Unlike the [slice cast][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D], which statically requires that `Dst` is no more aligned than `Src`, this tolerates misaligned input. It is a safe, total version of [`slice::align_to`][slice::align_to]: the bound below guarantees that every other requirement of the cast is met, and the alignment requirement is enforced dynamically.
+    /// ```rust
+    /// let (head, words, tail): (&[u8], &[u64], &[u8]) = cast_slice_prefix_suffix(bytes);
+    /// ```
+    /// As with `align_to`, the middle slice may be shorter than the maximum possible length.
+    #[inline(always)]
+    pub fn cast_slice_prefix_suffix<'i, Src, Dst>(src: &'i [Src]) -> (&'i [Src], &'i [Dst], &'i [Src])
+    where
+        &'i [Dst]: CastFrom<&'i [Src], NeglectAlignment>,
+    {
+        // Safe because the bound above ensures that `Dst` is bit-valid for any `Src` bytes, and
+        // `align_to` only produces a correctly aligned middle slice.
+        unsafe { src.align_to::<Dst>() }
+    }
+
+    /// Cast the maximal aligned middle of `&mut [Src]` into `&mut [Dst]`, returning the unconverted head and tail.
+    ///
+    /// The mutable counterpart of [cast_slice_prefix_suffix].
+    #[inline(always)]
+    pub fn cast_slice_prefix_suffix_mut<'i, Src, Dst>(src: &'i mut [Src]) -> (&'i mut [Src], &'i mut [Dst], &'i mut [Src])
+    where
+        &'i mut [Dst]: CastFrom<&'i mut [Src], NeglectAlignment>,
+    {
+        // Safe because the bound above ensures that `Src` and `Dst` are mutually bit-valid, and
+        // `align_to_mut` only produces a correctly aligned middle slice.
+        unsafe { src.align_to_mut::<Dst>() }
+    }
+
+    use crate::{
+        transmute::options::NeglectValidity,
+        validity::{CheckBitValidity, ValidityError, check_slice},
+    };
+
+    use core::mem::MaybeUninit;
+
+    /// View an initialized slice `&[T]` as a slice of possibly-uninitialized elements `&[MaybeUninit<T>]`.
+    ///
+    /// Every instance of `T` is a valid instance of `MaybeUninit<T>`, and no bytes may be written through the resulting shared slice, so this conversion is safe.
+    #[inline(always)]
+    pub fn as_uninit_slice<'a, T>(src: &'a [T]) -> &'a [MaybeUninit<T>]
+    where
+        &'a [MaybeUninit<T>]: CastFrom<&'a [T]>,
+    {
+        CastFrom::cast_from(src)
+    }
+
+    /// View an initialized slice `&mut [T]` as a slice of possibly-uninitialized elements `&mut [MaybeUninit<T>]`, for reuse as scratch space.
+    ///
+    /// Writes through the resulting slice may de-initialize elements of `src`; the static check that this cannot happen is neglected.
+    ///
+    /// # Safety
+    /// Every element of `src` must be initialized with a valid `T` before the borrow of `src` ends.
+    #[inline(always)]
+    pub unsafe fn as_uninit_slice_mut<'a, T>(src: &'a mut [T]) -> &'a mut [MaybeUninit<T>]
+    where
+        &'a mut [MaybeUninit<T>]: CastFrom<&'a mut [T], NeglectValidity>,
+    {
+        CastFrom::<_, NeglectValidity>::unsafe_cast_from(src)
+    }
+
+    /// Assume that every element of `&[MaybeUninit<T>]` is initialized, producing `&[T]`.
+    ///
+    /// The static check that every instance of `MaybeUninit<T>` is a valid `T` is neglected; all other requirements of the cast are checked statically.
+    ///
+    /// # Safety
+    /// Every element of `src` must be initialized with a valid `T`.
+    #[inline(always)]
+    pub unsafe fn assume_init_slice<'a, T>(src: &'a [MaybeUninit<T>]) -> &'a [T]
+    where
+        &'a [T]: CastFrom<&'a [MaybeUninit<T>], NeglectValidity>,
+    {
+        CastFrom::<_, NeglectValidity>::unsafe_cast_from(src)
+    }
+
+    /// Assume that every element of `&mut [MaybeUninit<T>]` is initialized, producing `&mut [T]`.
+    ///
+    /// The mutable counterpart of [assume_init_slice]:
+    /// ```rust
+    /// let mut buf = [MaybeUninit::<u32>::uninit(); 64];
+    /// let len = fill(&mut buf);
+    /// // Safe because `fill` initialized the first `len` elements.
+    /// let words: &mut [u32] = unsafe { assume_init_slice_mut(&mut buf[..len]) };
+    /// ```
+    ///
+    /// # Safety
+    /// Every element of `src` must be initialized with a valid `T`.
+    #[inline(always)]
+    pub unsafe fn assume_init_slice_mut<'a, T>(src: &'a mut [MaybeUninit<T>]) -> &'a mut [T]
+    where
+        &'a mut [T]: CastFrom<&'a mut [MaybeUninit<T>], NeglectValidity>,
+    {
+        CastFrom::<_, NeglectValidity>::unsafe_cast_from(src)
+    }
+
+    /// Cast a slice `&[Src]` into a slice `&[Dst]`, checking the validity of each element at runtime.
+    ///
+    /// The [slice cast][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D] statically requires that every instance of `Src` is a valid instance of `Dst`. This instead neglects that check, and dynamically verifies that each element of the resulting slice is bit-valid; e.g.:
+    /// ```rust
+    /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+    /// #[repr(u8)]
+    /// enum Opcode { Nop = 0, Push = 1, Pop = 2 }
+    ///
+    /// let program: &[Opcode] = try_cast_slice(bytes)?;
+    /// ```
+    #[inline]
+    pub fn try_cast_slice<'i, 'o, Src, Dst>(src: &'i [Src]) -> Result<&'o [Dst], ValidityError>
+    where
+        &'o [Dst]: CastFrom<&'i [Src], NeglectValidity>,
+        &'i [u8]: CastFrom<&'i [Src]>,
+        Dst: CheckBitValidity,
+    {
+        check_slice::<Dst>(CastFrom::cast_from(src))?;
+        // Safe because we've dynamically verified that every element is
+        // bit-valid, the static check for which we chose to neglect.
+        Ok(unsafe { CastFrom::<_, NeglectValidity>::unsafe_cast_from(src) })
+    }
+
+    /// Cast a slice `&[Src]` into a slice `&[Dst]`, checking the validity of each element at runtime, in parallel.
+    ///
+    /// For very large slices, the runtime validity checks of [try_cast_slice] dominate the cost of the cast. This splits the slice into blocks, which are validated in parallel on the [rayon](https://docs.rs/rayon) thread pool before the zero-copy cast is performed.
+    ///
+    /// ***This function requires the `rayon` feature.***
+    #[cfg(feature = "rayon")]
+    pub fn par_try_cast_slice<'i, 'o, Src, Dst>(src: &'i [Src]) -> Result<&'o [Dst], ValidityError>
+    where
+        &'o [Dst]: CastFrom<&'i [Src], NeglectValidity>,
+        &'i [u8]: CastFrom<&'i [Src]>,
+        Dst: CheckBitValidity,
+    {
+        use rayon::prelude::*;
+
+        /// The approximate number of bytes validated by each task.
+        const BLOCK: usize = 1 << 16;
+
+        let bytes: &[u8] = CastFrom::cast_from(src);
+        let size = core::mem::size_of::<Dst>();
+        let block = (BLOCK / size).max(1) * size;
+        // Of the invalid blocks, the first is reported, with offsets relative
+        // to the whole slice, so that errors match those of `try_cast_slice`.
+        let invalid = bytes[..bytes.len() / size * size]
+            .par_chunks(block)
+            .enumerate()
+            .find_map_first(|(i, chunk)| check_slice::<Dst>(chunk).err().map(|err| err.offset_by(i * block)));
+        if let Some(err) = invalid {
+            return Err(err);
+        }
+        // Safe because we've dynamically verified that every element is
+        // bit-valid, the static check for which we chose to neglect.
+        Ok(unsafe { CastFrom::<_, NeglectValidity>::unsafe_cast_from(src) })
+    }
+
+    /// Cast a `Vec<Src>` into a `Vec<Dst>`, reallocating if the layouts of `Src` and `Dst` prevent reusing the allocation.
+    ///
+    /// The [`Vec` cast][CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] is zero-copy, and so statically requires that `Src` and `Dst` have equal size and alignment. In general, `Vec<u8>` can never be cast into `Vec<u64>` this way, since the allocation of a `Vec<u8>` need not be aligned for `u64`. This function instead copies the contents of `src` into a new, correctly aligned allocation; e.g.:
+    /// ```rust
+    /// let words: Vec<u64> = cast_vec_realloc(bytes);
+    /// ```
+    /// If `Src` and `Dst` happen to have equal size and alignment, the allocation of `src` is reused instead. As with [slice casts][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D], any trailing bytes of `src` that do not fill a whole `Dst` are discarded.
+    pub fn cast_vec_realloc<Src, Dst>(mut src: Vec<Src>) -> Vec<Dst>
+    where
+        for<'a> &'a [Dst]: CastFrom<&'a [Src], NeglectAlignment>,
+    {
+        use core::{mem::{align_of, size_of, size_of_val}, ptr};
+
+        if size_of::<Src>() == size_of::<Dst>() && align_of::<Src>() == align_of::<Dst>() {
+            let (ptr, len, cap) = src.into_raw_parts();
+            // Safe because the layouts of `Src` and `Dst` are identical, and the bound
+            // above guarantees every other requirement of the cast.
+            return unsafe { Vec::from_raw_parts(ptr.cast::<Dst>(), len, cap) };
+        }
+
+        let len = size_of_val(&src[..]) / size_of::<Dst>();
+        let mut dst = Vec::<Dst>::with_capacity(len);
+        unsafe {
+            // Safe because the bound above guarantees that the bytes of `src` are
+            // valid `Dst`s, and `dst` is freshly allocated with the alignment of `Dst`.
+            ptr::copy_nonoverlapping(src.as_ptr().cast::<u8>(), dst.as_mut_ptr().cast::<u8>(), len * size_of::<Dst>());
+            dst.set_len(len);
+            // The elements of `src` are now owned by `dst`; free the allocation without dropping them.
+            src.set_len(0);
+        }
+        dst
+    }
+
+    /// Cast the leading elements of a `Vec<Src>` into a `Vec<Dst>`, returning the trailing elements that do not fill a whole `Dst`.
+    ///
+    /// [cast_vec_realloc] discards trailing bytes that do not fill a whole `Dst`. Streaming decoders instead want to keep them, to prepend to the next buffer; e.g.:
+    /// ```rust
+    /// let (words, rest): (Vec<u32>, Vec<u8>) = cast_vec_with_remainder(buf);
+    /// decode(&words);
+    /// buf = rest;
+    /// ```
+    /// The split is made at the last element boundary of `src` that is also a `Dst` boundary, so the remainder holds fewer elements than fill the least common multiple of `size_of::<Src>()` and `size_of::<Dst>()`.
+    pub fn cast_vec_with_remainder<Src, Dst>(mut src: Vec<Src>) -> (Vec<Dst>, Vec<Src>)
+    where
+        for<'a> &'a [Dst]: CastFrom<&'a [Src], NeglectAlignment>,
+    {
+        use core::mem::size_of;
+
+        fn gcd(a: usize, b: usize) -> usize {
+            if b == 0 { a } else { gcd(b, a % b) }
+        }
+
+        let (s, d) = (size_of::<Src>().max(1), size_of::<Dst>().max(1));
+        // The number of `Src` elements spanning a whole number of `Dst`s.
+        let step = d / gcd(s, d);
+        let rest = src.split_off(src.len() / step * step);
+        (cast_vec_realloc(src), rest)
+    }
+
+    use std::borrow::Cow;
+
+    /// Cast a slice of bytes into a slice of `T`, copying it into a new allocation only if it is misaligned.
+    ///
+    /// The [slice cast][CastFrom#impl-CastFrom<%26%27i%20%5BSrc%5D%2C%20Neglect>-for-%26%27o%20%5BDst%5D] from `&[u8]` statically requires that `T` has an alignment of `1`. The usual recovery, for types of greater alignment, is to copy the bytes into a correctly aligned `Vec<T>`. This function performs exactly that, but only when `bytes` is actually misaligned; e.g.:
+    /// ```rust
+    /// let samples: Cow<[f32]> = cast_slice_to_owned(&buf[HEADER_LEN..]);
+    /// ```
+    /// As with slice casts, any trailing bytes that do not fill a whole `T` are discarded.
+    pub fn cast_slice_to_owned<'a, T>(bytes: &'a [u8]) -> Cow<'a, [T]>
+    where
+        T: Clone,
+        &'a [T]: CastFrom<&'a [u8], NeglectAlignment>,
+    {
+        use core::{mem::size_of, ptr};
+
+        if crate::mem::runtime::is_aligned_to::<T>(bytes.as_ptr()) {
+            // Safe because we've dynamically verified the alignment
+            // requirement, whose static check we chose to neglect.
+            return Cow::Borrowed(unsafe { CastFrom::<_, NeglectAlignment>::unsafe_cast_from(bytes) });
+        }
+
+        let len = bytes.len() / size_of::<T>();
+        let mut dst = Vec::<T>::with_capacity(len);
+        unsafe {
+            // Safe because the bound above guarantees that the bytes are valid
+            // `T`s, and `dst` is freshly allocated with the alignment of `T`.
+            ptr::copy_nonoverlapping(bytes.as_ptr(), dst.as_mut_ptr().cast::<u8>(), len * size_of::<T>());
+            dst.set_len(len);
+        }
+        Cow::Owned(dst)
+    }
+
+    use crate::mem::{AlignEq, SizeEq};
+
+    /// Cast a `Vec<Src>` into a `Vec<Dst>`, guaranteeing that the allocation of `src` is reused.
+    ///
+    /// This is the [`Vec` cast][CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>], with its requirement that `Src` and `Dst` have equal size and alignment stated in its signature. Latency-sensitive code may thereby rule out hidden copies: unlike [cast_vec_realloc], or the cast into `Box<[Dst]>`, this fails to compile wherever the allocation could not be reused as-is; e.g.:
+    /// ```rust
+    /// let pixels: Vec<[u8; 4]> = cast_vec_zero_copy(rgba); // Ok! (`rgba: Vec<Rgba>`, where `Rgba` is four `u8`s)
+    /// let words: Vec<u32> = cast_vec_zero_copy(bytes);     // Compile Error!
+    /// ```
+    #[inline(always)]
+    pub fn cast_vec_zero_copy<Src, Dst>(src: Vec<Src>) -> Vec<Dst>
+    where
+        Src: SizeEq<Dst> + AlignEq<Dst>,
+        Vec<Dst>: CastFrom<Vec<Src>>,
+    {
+        CastFrom::cast_from(src)
+    }
+
+    /// Cast a `Box<Src>` into a `Box<Dst>`, guaranteeing that the allocation of `src` is reused.
+    ///
+    /// See [cast_vec_zero_copy].
+    #[inline(always)]
+    pub fn cast_box_zero_copy<Src, Dst>(src: Box<Src>) -> Box<Dst>
+    where
+        Src: SizeEq<Dst> + AlignEq<Dst>,
+        Box<Dst>: CastFrom<Box<Src>>,
+    {
+        CastFrom::cast_from(src)
+    }
+
+    use core::str::Utf8Error;
+    use std::{rc::Rc, sync::Arc};
+    use crate::validity::check_utf8;
+
+    /// Cast an `Arc<[u8]>` into an `Arc<str>`, validating that its contents are UTF-8.
+    ///
+    /// The contents are validated once, and the allocation is then reused, so reference-counted string slices may be handed out from network buffers without copying:
+    /// ```rust
+    /// let name: Arc<str> = try_cast_arc_str(Arc::clone(&buf))?;
+    /// ```
+    /// The reverse direction is provided by [CastFrom].
+    #[inline]
+    pub fn try_cast_arc_str(src: Arc<[u8]>) -> Result<Arc<str>, Utf8Error>
+    {
+        check_utf8(&src)?;
+        // Safe because `str` and `[u8]` have the same layout, and we've
+        // dynamically verified that the bytes are valid UTF-8.
+        Ok(unsafe { Arc::from_raw(Arc::into_raw(src) as *const str) })
+    }
+
+    /// Cast an `Rc<[u8]>` into an `Rc<str>`, validating that its contents are UTF-8.
+    ///
+    /// The single-threaded counterpart of [try_cast_arc_str].
+    #[inline]
+    pub fn try_cast_rc_str(src: Rc<[u8]>) -> Result<Rc<str>, Utf8Error>
+    {
+        check_utf8(&src)?;
+        // Safe because `str` and `[u8]` have the same layout, and we've
+        // dynamically verified that the bytes are valid UTF-8.
+        Ok(unsafe { Rc::from_raw(Rc::into_raw(src) as *const str) })
+    }
+
+    use std::{ffi::CStr, os::raw::c_char};
+
+    /// Cast a slice of C characters into a slice of bytes.
+    ///
+    /// [c_char] is `i8` on some targets and `u8` on others; this cast is available on every target.
+    #[inline(always)]
+    pub fn c_chars_as_bytes(src: &[c_char]) -> &[u8]
+    {
+        CastFrom::cast_from(src)
+    }
+
+    /// Cast a slice of bytes into a slice of C characters.
+    ///
+    /// The reciprocal of [c_chars_as_bytes].
+    #[inline(always)]
+    pub fn bytes_as_c_chars(src: &[u8]) -> &[c_char]
+    {
+        CastFrom::cast_from(src)
+    }
+
+    /// Mutate the bytes of a `str` in place, re-validating them as UTF-8 once `f` returns.
+    ///
+    /// This is the scoped equivalent of [`str::as_bytes_mut`]: `&mut str -> &mut [u8]` is not a safe cast, since the bytes may be left as invalid UTF-8, but a view which restores validity before the `str` is usable again is safe. If the bytes are not valid UTF-8 when `f` returns (or unwinds), they are zeroed, and an error is returned; e.g.:
+    /// ```rust
+    /// let mut name = String::from("hello");
+    /// with_str_bytes_mut(&mut name, |bytes| bytes.make_ascii_uppercase())?;
+    /// assert_eq!(name, "HELLO");
+    /// ```
+    pub fn with_str_bytes_mut<R, F>(src: &mut str, f: F) -> Result<R, Utf8Error>
+    where
+        F: FnOnce(&mut [u8]) -> R,
+    {
+        // Restores the validity of the bytes when dropped. Since this guard
+        // is never exposed to `f`, it cannot be leaked.
+        struct Restore<'a>(&'a mut [u8]);
+
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                if check_utf8(&*self.0).is_err() {
+                    self.0.iter_mut().for_each(|b| *b = 0);
+                }
+            }
+        }
+
+        // Safe because `guard` re-establishes the UTF-8 validity of the bytes
+        // before `src` is usable again, even if `f` panics.
+        let mut guard = Restore(unsafe { src.as_bytes_mut() });
+        let ret = f(&mut *guard.0);
+        match check_utf8(&*guard.0) {
+            Ok(()) => Ok(ret),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// View a reference to `Src` as a reference to its leading `Dst`.
+    ///
+    /// Transmutations of references may shrink their referents: the bytes of a `&Src` beyond `size_of::<Dst>()` are simply not visible through the resulting `&Dst`. This permits cheap inspection of the headers of larger records, without copying, and without knowledge of the remainder of their layout; e.g.:
+    /// ```rust
+    /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+    /// #[repr(C)]
+    /// pub struct Record { pub header: Header, pub payload: [u8; 4000] }
+    ///
+    /// let header: &Header = prefix_of(&record);
+    /// ```
+    /// For mutable references, whose writes must leave behind a valid `Src`, see [PermitPrefix][options::PermitPrefix].
+    #[inline(always)]
+    pub fn prefix_of<'a, Src, Dst>(src: &'a Src) -> &'a Dst
+    where
+        &'a Dst: crate::transmute::TransmuteFrom<&'a Src>,
+    {
+        crate::transmute::TransmuteFrom::transmute_from(src)
+    }
+
+    /// Types whose valid bit patterns are a strict subset of those of `Self::Superset`.
+    ///
+    /// For these types, the cast `&[Self] -> &[Self::Superset]` is always safe, but its reverse must be validated:
+    ///
+    /// | `Self`             | `Self::Superset`   |
+    /// |--------------------|--------------------|
+    /// | `bool`             | `u8`               |
+    /// | `char`             | `u32`              |
+    /// | `NonZeroU32`, etc. | `u32`, etc.        |
+    ///
+    /// For field-less enums promising their layout, see [discriminant_bits_slice][crate::mem::discriminant_bits_slice] and [try_cast_slice].
+    pub trait OneWayValidity: CheckBitValidity + Sized {
+        /// The type whose bit patterns include all of `Self`'s.
+        type Superset;
+    }
+
+    macro_rules! one_way_validity {
+        ($($ty:ty => $superset:ty),* $(,)?) => {$(
+            impl OneWayValidity for $ty { type Superset = $superset; }
+        )*}
+    }
+
+    one_way_validity! {
+        bool => u8,
+        char => u32,
+        core::num::NonZeroI8    => i8,
+        core::num::NonZeroI16   => i16,
+        core::num::NonZeroI32   => i32,
+        core::num::NonZeroI64   => i64,
+        core::num::NonZeroI128  => i128,
+        core::num::NonZeroIsize => isize,
+        core::num::NonZeroU8    => u8,
+        core::num::NonZeroU16   => u16,
+        core::num::NonZeroU32   => u32,
+        core::num::NonZeroU64   => u64,
+        core::num::NonZeroU128  => u128,
+        core::num::NonZeroUsize => usize,
+    }
+
+    /// Cast a slice of `T` into a slice of its [superset][OneWayValidity]; e.g., `&[bool]` into `&[u8]`.
+    #[inline(always)]
+    pub fn as_superset_slice<'a, T>(src: &'a [T]) -> &'a [T::Superset]
+    where
+        T: OneWayValidity,
+        &'a [T::Superset]: CastFrom<&'a [T]>,
+    {
+        CastFrom::cast_from(src)
+    }
+
+    /// Cast a slice of the [superset][OneWayValidity] of `T` into a slice of `T`, checking the validity of each element at runtime; e.g., `&[u32]` into `&[char]`.
+    #[inline]
+    pub fn try_from_superset_slice<'a, T>(src: &'a [T::Superset]) -> Result<&'a [T], ValidityError>
+    where
+        T: OneWayValidity,
+        &'a [T]: CastFrom<&'a [T::Superset], NeglectValidity>,
+        &'a [u8]: CastFrom<&'a [T::Superset]>,
+    {
+        try_cast_slice(src)
+    }
+
+    /// Cast a slice of bytes into a `CStr`, validating that it ends with its only nul byte.
+    ///
+    /// Interior nul bytes, and a missing trailing nul, are reported as a [ValidityError]. The reverse casts, from `&CStr` into `&[u8]` with or without the trailing nul, are infallible; see [WithNul][options::WithNul].
+    ///
+    /// Like [try_cast_slice], this neglects a validity requirement statically, and checks it dynamically:
+    /// ```rust
+    /// let name: &CStr = try_cast_cstr(b"eth0\0")?;
+    /// unsafe { if_nametoindex(name.as_ptr()) };
+    /// ```
+    #[inline]
+    pub fn try_cast_cstr(src: &[u8]) -> Result<&CStr, ValidityError>
+    {
+        match src.iter().position(|&b| b == 0) {
+            // Safe because we've dynamically verified that the only nul byte
+            // is the last.
+            Some(nul) if nul + 1 == src.len() => Ok(unsafe { CStr::from_bytes_with_nul_unchecked(src) }),
+            _ => Err(ValidityError::new()),
+        }
+    }
+
+    use core::pin::Pin;
+
+    /// Cast a `Pin<Box<Src>>` into a `Pin<Box<Dst>>`, where `Src` may be `!Unpin`.
+    ///
+    /// The layout requirements of the [`Box` cast][ContainerCast#impl-ContainerCast<Box<Dst>>-for-Box<Src>] are checked statically, but the pinning requirements are not.
+    ///
+    /// # Safety
+    /// Every pinning guarantee made by `Src` must be upheld by `Dst`: each field of `Src` that is structurally pinned must overlap only with a structurally pinned field of `Dst` with the same pinning requirements, and `Dst` must not be `Unpin` unless `Src` is.
+    #[inline(always)]
+    pub unsafe fn cast_pin_box_unchecked<Src, Dst, Neglect>(src: Pin<Box<Src>>) -> Pin<Box<Dst>>
+    where
+        Neglect: UnsafeBoxCastOptions,
+        Box<Dst>: CastFrom<Box<Src>, Neglect>,
+    {
+        let src = Pin::into_inner_unchecked(src);
+        Pin::new_unchecked(CastFrom::<_, Neglect>::unsafe_cast_from(src))
+    }
+
+    /// (Extension) A `#[repr(transparent)]` wrapper around `Inner`.
+    ///
+    /// Converting between a newtype and the type it wraps is the most frequent reason to reach for [mem::transmute][core::mem::transmute]. This trait provides those conversions safely, for values, references, slices and vectors. Each conversion is justified by the bounds of [TransmuteFrom] and [CastFrom], so implementing this trait is safe; it is usually [derived][macro@TransparentWrapper]:
+    /// ```rust
+    /// #[derive(TransparentWrapper, PromiseTransmutableFrom, PromiseTransmutableInto)]
+    /// #[repr(transparent)]
+    /// pub struct Meters(pub f64);
+    ///
+    /// let distances: &[Meters] = Meters::wrap_slice(&[1.0, 2.5, 4.0]);
+    /// let raw: Vec<f64> = Meters::peel_vec(vec![Meters(3.0)]);
+    /// ```
+    #[unstable(feature = "transparent_wrapper", issue = "none")]
+    pub trait TransparentWrapper<Inner>: Sized {
+        /// Wrap a value of the inner type.
+        #[inline(always)]
+        fn wrap(inner: Inner) -> Self
+        where
+            Self: TransmuteFrom<Inner>,
+        {
+            Self::transmute_from(inner)
+        }
+
+        /// Peel the wrapper off of a value, producing the inner type.
+        #[inline(always)]
+        fn peel(self) -> Inner
+        where
+            Inner: TransmuteFrom<Self>,
+        {
+            Inner::transmute_from(self)
+        }
+
+        /// Wrap a reference to the inner type.
+        #[inline(always)]
+        fn wrap_ref(inner: &Inner) -> &Self
+        where
+            for<'a> &'a Self: CastFrom<&'a Inner>,
+        {
+            CastFrom::cast_from(inner)
+        }
+
+        /// Peel the wrapper off of a reference, producing a reference to the inner type.
+        #[inline(always)]
+        fn peel_ref(&self) -> &Inner
+        where
+            for<'a> &'a Inner: CastFrom<&'a Self>,
+        {
+            CastFrom::cast_from(self)
+        }
+
+        /// Wrap a mutable reference to the inner type.
+        #[inline(always)]
+        fn wrap_mut(inner: &mut Inner) -> &mut Self
+        where
+            for<'a> &'a mut Self: CastFrom<&'a mut Inner>,
+        {
+            CastFrom::cast_from(inner)
+        }
+
+        /// Peel the wrapper off of a mutable reference, producing a mutable reference to the inner type.
+        #[inline(always)]
+        fn peel_mut(&mut self) -> &mut Inner
+        where
+            for<'a> &'a mut Inner: CastFrom<&'a mut Self>,
+        {
+            CastFrom::cast_from(self)
+        }
+
+        /// Wrap each element of a slice of the inner type.
+        #[inline(always)]
+        fn wrap_slice(inner: &[Inner]) -> &[Self]
+        where
+            for<'a> &'a [Self]: CastFrom<&'a [Inner]>,
+        {
+            CastFrom::cast_from(inner)
+        }
+
+        /// Peel the wrapper off of each element of a slice.
+        #[inline(always)]
+        fn peel_slice(slice: &[Self]) -> &[Inner]
+        where
+            for<'a> &'a [Inner]: CastFrom<&'a [Self]>,
+        {
+            CastFrom::cast_from(slice)
+        }
+
+        /// Wrap each element of a vector of the inner type, without reallocating.
+        #[inline(always)]
+        fn wrap_vec(inner: Vec<Inner>) -> Vec<Self>
+        where
+            Vec<Self>: CastFrom<Vec<Inner>>,
+        {
+            CastFrom::cast_from(inner)
+        }
+
+        /// Peel the wrapper off of each element of a vector, without reallocating.
+        #[inline(always)]
+        fn peel_vec(vec: Vec<Self>) -> Vec<Inner>
+        where
+            Vec<Inner>: CastFrom<Vec<Self>>,
+        {
+            CastFrom::cast_from(vec)
+        }
+    }
+
+    #[doc(hidden)]
+    mod macros {
+        /// Derive macro generating an impl of the trait [TransparentWrapper][trait@super::TransparentWrapper].
+        ///
+        /// The type must be `#[repr(transparent)]`; its single non-zero-sized field is the `Inner` type. For instance, this:
+        /// ```rust
+        /// #[derive(TransparentWrapper)]
+        /// #[repr(transparent)]
+        /// pub struct Meters(pub f64);
+        /// ```
+        /// will expand to this:
+        /// ```rust
+        /// impl TransparentWrapper<f64> for Meters {}
+        /// ```
+        #[unstable(feature = "transparent_wrapper", issue = "none")]
+        pub macro TransparentWrapper($item:item) {
+            /* compiler built-in */
+        }
+    }
+
+    #[doc(inline)]
+    pub use macros::TransparentWrapper;
+
+    /// Options for casting.
+    pub mod options {
+
+        /// The super-trait of all safe casting options.
+        #[marker] pub trait SafeCastOptions: UnsafeCastOptions {}
+
+        /// The super-trait of all unsafe casting options.
+        #[marker] pub trait UnsafeCastOptions {}
+
+        impl SafeCastOptions for () {}
+        impl UnsafeCastOptions for () {}
+
+        pub use slice::{
+            SafeSliceCastOptions,
+            UnsafeSliceCastOptions,
+            PermitZeroSized,
+            RequireExactFit,
+            ExactFitError,
+        };
+
+        pub use vec::{
+            SafeVecCastOptions,
+            UnsafeVecCastOptions,
+        };
+
+        pub use array::{
+            SafeArrayCastOptions,
+            UnsafeArrayCastOptions,
+        };
+
+        pub use boxed::{
+            SafeBoxCastOptions,
+            UnsafeBoxCastOptions,
+        };
+
+        pub use container::{
+            SafeContainerCastOptions,
+            UnsafeContainerCastOptions,
+        };
+
+        pub use prefix::PermitPrefix;
+
+        pub use pointer::NeglectDereferenceability;
+
+        pub use cstr::WithNul;
+
+        pub use atomic::PermitInteriorMutability;
+
+        /// Options for casting the contents of slices.
+        mod slice {
+            use super::{
+                SafeCastOptions,
+                UnsafeCastOptions,
+                super::CastFrom,
+                super::super::transmute::{
+                    TransmuteFrom,
+                    options::{PreservesSize, SafeTransmuteOptions, UnsafeTransmuteOptions},
+                },
+            };
+
+            use core::{
+                fmt,
+                marker::PhantomData,
+                mem::{size_of, size_of_val},
+                slice
+            };
+
+            use crate::mem::NonZeroSized;
+
+            /// Safe options for casting **slices**.
+            ///
+            /// Slice casting transmutes the contents of the slice, and adjusts the slice's length as needed. All [SafeTransmuteOptions] are [SafeSliceCastOptions].
+            pub trait SafeSliceCastOptions
+                : SafeCastOptions
+                + SafeTransmuteOptions
+                + UnsafeSliceCastOptions
+            {}
+
+            /// Unsafe options for casting **slices**.
+            ///
+            /// Slice casting transmutes the contents of the slice, and adjusts the slice's length as needed. All [UnsafeTransmuteOptions] are [UnsafeSliceCastOptions].
+            ///
+            /// Casting into a slice of a zero-sized type would discard the contents of the source slice, so these options statically reject zero-sized `Dst` types. To cast into such a slice anyways, wrap the options in [PermitZeroSized].
+            pub trait UnsafeSliceCastOptions
+                : UnsafeCastOptions
+                + UnsafeTransmuteOptions
+            {}
+
+            impl<Neglect: SafeTransmuteOptions> SafeCastOptions for Neglect {}
+            impl<Neglect: SafeTransmuteOptions> SafeSliceCastOptions for Neglect {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeCastOptions for Neglect {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeSliceCastOptions for Neglect {}
+
+            /// Permit slice casts into slices of zero-sized types.
+            ///
+            /// By default, slice casts are not implemented when `Dst` is zero-sized: no number of zero-sized elements is a faithful reinterpretation of the source's bytes. This option permits such casts, with the transmutation options `Neglect`. The length of the resulting slice is:
+            ///  - `size_of_val(src) / size_of::<Dst>()`, if `Dst` is not zero-sized; otherwise
+            ///  - `src.len()`; i.e., each `Src` element is reinterpreted as exactly one `Dst` element.
+            ///
+            /// If `Src` is zero-sized and `Dst` is not, the source slice contains no bytes, and the resulting slice is always empty. This holds regardless of this option.
+            /// ```rust
+            /// let markers: &[PhantomData<Packet>] = CastFrom::<_, PermitZeroSized>::cast_from(packets);
+            /// assert_eq!(markers.len(), packets.len());
+            /// ```
+            pub struct PermitZeroSized<Neglect = ()>(PhantomData<Neglect>);
+
+            impl<Neglect: SafeTransmuteOptions> SafeCastOptions for PermitZeroSized<Neglect> {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeCastOptions for PermitZeroSized<Neglect> {}
+
+            /// Require that slice casts reinterpret *every* byte of the source slice.
+            ///
+            /// By default, if the size of the source slice is not a multiple of `size_of::<Dst>()`, the trailing bytes of the source are excluded from the resulting slice. This option instead casts into a `Result`, which is an [ExactFitError] if any bytes are left over, with the transmutation options `Neglect`:
+            /// ```rust
+            /// let words: Result<&[u32], _> = CastFrom::<_, RequireExactFit>::cast_from(&[0u8; 8][..]); // Ok!
+            /// let words: Result<&[u32], _> = CastFrom::<_, RequireExactFit>::cast_from(&[0u8; 7][..]); // Err!
+            /// ```
+            /// If `size_of::<Src>()` is a multiple of `size_of::<Dst>()`, every source slice fits exactly, and the check is statically eliminated.
+            ///
+            /// This option is a strictness marker, and so `Neglect` must [preserve the size check][crate::transmute::options::PreservesSize]; e.g., `RequireExactFit<NeglectSize>` is rejected at compile time.
+            pub struct RequireExactFit<Neglect = ()>(PhantomData<Neglect>);
+
+            impl<Neglect: SafeTransmuteOptions + PreservesSize> SafeCastOptions for RequireExactFit<Neglect> {}
+            impl<Neglect: UnsafeTransmuteOptions + PreservesSize> UnsafeCastOptions for RequireExactFit<Neglect> {}
+
+            /// The error produced when a [RequireExactFit] cast would not reinterpret every byte of its source.
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct ExactFitError {
+                /// The number of trailing bytes of the source that do not fill a whole element of the destination.
+                pub remainder: usize,
+            }
+
+            impl fmt::Display for ExactFitError {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "slice cast does not fit exactly: {} trailing bytes", self.remainder)
+                }
+            }
+
+            impl std::error::Error for ExactFitError {}
+
+            #[inline(always)]
+            fn exact_len<Src, Dst>(src: &[Src]) -> Result<usize, ExactFitError>
+            {
+                let size = size_of_val(src);
+                match size % size_of::<Dst>() {
+                    0 => Ok(size / size_of::<Dst>()),
+                    remainder => Err(ExactFitError { remainder }),
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&[Src]` into a slice `&[Dst]`
+            ///
+            /// </h2>
+            ///
+            /// The arrays `[Src; size_of::<Dst>()]` and `[Dst; size_of::<Src>()]` have the same size, `size_of::<Src>() * size_of::<Dst>()`. Every slice cast can therefore be checked statically by transmuting references to these arrays, which requires that the elements are compatible in alignment, validity and stability, regardless of the length of the slice. Downstream code may name this bound in its own signatures.
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i [Src], Neglect> for &'o [Dst]
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o [Dst; size_of::<Src>()]: TransmuteFrom<&'i [Src; size_of::<Dst>()], Neglect>,
+                Dst: NonZeroSized<Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i [Src]) -> &'o [Dst]
+                where
+                    Neglect: UnsafeSliceCastOptions,
+                {
+                    let len = size_of_val(src) / size_of::<Dst>();
+                    unsafe { slice::from_raw_parts(src.as_ptr().cast::<Dst>(), len) }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&mut [Src]` into a slice `&mut [Dst]`
+            ///
+            /// </h2>
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], Neglect> for &'o mut [Dst]
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o mut [Dst; size_of::<Src>()]: TransmuteFrom<&'i mut [Src; size_of::<Dst>()], Neglect>,
+                Dst: NonZeroSized<Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> &'o mut [Dst]
+                where
+                    Neglect: UnsafeSliceCastOptions,
+                {
+                    let len = size_of_val(src) / size_of::<Dst>();
+                    unsafe { slice::from_raw_parts_mut(src.as_mut_ptr().cast::<Dst>(), len) }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&mut [Src]` into a slice `&mut [Dst]`
+            ///
+            /// </h2>
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], Neglect> for &'o [Dst]
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o mut [Dst; size_of::<Src>()]: TransmuteFrom<&'i [Src; size_of::<Dst>()], Neglect>,
+                Dst: NonZeroSized<Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> &'o [Dst]
+                where
+                    Neglect: UnsafeSliceCastOptions,
+                {
+                    let len = size_of_val(src) / size_of::<Dst>();
+                    unsafe {
+                        slice::from_raw_parts(src.as_ptr().cast::<Dst>(), len)
+                    }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&[Src]` into a slice `&[Dst]`, permitting zero-sized `Dst`
+            ///
+            /// </h2>
+            ///
+            /// See [PermitZeroSized].
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i [Src], PermitZeroSized<Neglect>> for &'o [Dst]
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o [Dst; size_of::<Src>()]: TransmuteFrom<&'i [Src; size_of::<Dst>()], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i [Src]) -> &'o [Dst]
+                where
+                    PermitZeroSized<Neglect>: UnsafeCastOptions,
+                {
+                    let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(src.len());
+                    unsafe { slice::from_raw_parts(src.as_ptr().cast::<Dst>(), len) }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&mut [Src]` into a slice `&mut [Dst]`, permitting zero-sized `Dst`
+            ///
+            /// </h2>
+            ///
+            /// See [PermitZeroSized].
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], PermitZeroSized<Neglect>> for &'o mut [Dst]
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o mut [Dst; size_of::<Src>()]: TransmuteFrom<&'i mut [Src; size_of::<Dst>()], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> &'o mut [Dst]
+                where
+                    PermitZeroSized<Neglect>: UnsafeCastOptions,
+                {
+                    let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(src.len());
+                    unsafe { slice::from_raw_parts_mut(src.as_mut_ptr().cast::<Dst>(), len) }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&mut [Src]` into a slice `&[Dst]`, permitting zero-sized `Dst`
+            ///
+            /// </h2>
+            ///
+            /// See [PermitZeroSized].
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], PermitZeroSized<Neglect>> for &'o [Dst]
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o mut [Dst; size_of::<Src>()]: TransmuteFrom<&'i [Src; size_of::<Dst>()], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> &'o [Dst]
+                where
+                    PermitZeroSized<Neglect>: UnsafeCastOptions,
+                {
+                    let len = size_of_val(src).checked_div(size_of::<Dst>()).unwrap_or(src.len());
+                    unsafe {
+                        slice::from_raw_parts(src.as_ptr().cast::<Dst>(), len)
+                    }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&[Src]` into a slice `&[Dst]`, requiring an exact fit
+            ///
+            /// </h2>
+            ///
+            /// See [RequireExactFit].
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i [Src], RequireExactFit<Neglect>> for Result<&'o [Dst], ExactFitError>
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o [Dst; size_of::<Src>()]: TransmuteFrom<&'i [Src; size_of::<Dst>()], Neglect>,
+                Dst: NonZeroSized<Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i [Src]) -> Result<&'o [Dst], ExactFitError>
+                where
+                    RequireExactFit<Neglect>: UnsafeCastOptions,
+                {
+                    let len = exact_len::<Src, Dst>(src)?;
+                    Ok(unsafe { slice::from_raw_parts(src.as_ptr().cast::<Dst>(), len) })
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&mut [Src]` into a slice `&mut [Dst]`, requiring an exact fit
+            ///
+            /// </h2>
+            ///
+            /// See [RequireExactFit].
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], RequireExactFit<Neglect>> for Result<&'o mut [Dst], ExactFitError>
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o mut [Dst; size_of::<Src>()]: TransmuteFrom<&'i mut [Src; size_of::<Dst>()], Neglect>,
+                Dst: NonZeroSized<Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> Result<&'o mut [Dst], ExactFitError>
+                where
+                    RequireExactFit<Neglect>: UnsafeCastOptions,
+                {
+                    let len = exact_len::<Src, Dst>(src)?;
+                    Ok(unsafe { slice::from_raw_parts_mut(src.as_mut_ptr().cast::<Dst>(), len) })
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a slice `&mut [Src]` into a slice `&[Dst]`, requiring an exact fit
+            ///
+            /// </h2>
+            ///
+            /// See [RequireExactFit].
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut [Src], RequireExactFit<Neglect>> for Result<&'o [Dst], ExactFitError>
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o mut [Dst; size_of::<Src>()]: TransmuteFrom<&'i [Src; size_of::<Dst>()], Neglect>,
+                Dst: NonZeroSized<Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut [Src]) -> Result<&'o [Dst], ExactFitError>
+                where
+                    RequireExactFit<Neglect>: UnsafeCastOptions,
+                {
+                    let len = exact_len::<Src, Dst>(src)?;
+                    Ok(unsafe {
+                        slice::from_raw_parts(src.as_ptr().cast::<Dst>(), len)
+                    })
+                }
+            }
+
+        }
+
+        // Options for casting the contents of vecs.
+        mod vec {
+            use super::{
+                SafeCastOptions,
+                UnsafeCastOptions,
+                slice::{SafeSliceCastOptions, UnsafeSliceCastOptions},
+                super::CastFrom,
+                super::super::transmute::{
+                    TransmuteFrom,
+                    options::{SafeTransmuteOptions, UnsafeTransmuteOptions},
+                },
+            };
+
+            /// Safe options for casting **Vec**.
+            ///
+            /// Vec casting transmutes the contents of the vec, and adjusts the vec's length as needed. All [SafeTransmuteOptions] are [SafeVecCastOptions].
+            pub trait SafeVecCastOptions
+                : UnsafeVecCastOptions
+            {}
+
+            /// Unsafe options for casting **Vec**.
+            ///
+            /// Vec casting transmutes the contents of the vec, and adjusts the vec's length as needed. All [UnsafeTransmuteOptions] are [UnsafeVecCastOptions].
+            pub trait UnsafeVecCastOptions
+                : UnsafeTransmuteOptions
+                + UnsafeCastOptions
+            {}
+
+            impl<Neglect: SafeTransmuteOptions> SafeVecCastOptions for Neglect {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeVecCastOptions for Neglect {}
+
+            use core::mem::MaybeUninit;
+            use crate::mem::{SizeEq, AlignEq};
+
+            /// <h2>
+            ///
+            /// Cast a `Vec<Src>` into a `Vec<Dst>`
+            ///
+            /// </h2>
+            ///
+            /// [`Vec::from_raw_parts`][Vec::from_raw_parts] requires that the size and alignment of `Src` and `Dst` be equal. We can use the [AlignEq] and [SizeEq] gadgets to enforce these invariants statically.
+            impl<Src, Dst, Neglect> CastFrom<Vec<Src>, Neglect> for Vec<Dst>
+            where
+                Neglect: UnsafeVecCastOptions,
+                Dst: TransmuteFrom<Src, Neglect>
+                   + AlignEq<Dst, Neglect>
+                   + SizeEq<Dst, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Vec<Src>) -> Vec<Dst>
+                where
+                    Neglect: UnsafeVecCastOptions,
+                {
+                    let (ptr, len, cap) = src.into_raw_parts();
+                    Vec::from_raw_parts(ptr.cast::<Dst>(), len, cap)
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `Box<[Src]>` into a `Vec<Dst>`
+            ///
+            /// </h2>
+            ///
+            /// Equivalent to [`into_vec`][slice::into_vec] followed by the [`Vec` cast][CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>], neither of which reallocates.
+            impl<Src, Dst, Neglect> CastFrom<Box<[Src]>, Neglect> for Vec<Dst>
+            where
+                Neglect: UnsafeVecCastOptions,
+                Vec<Dst>: CastFrom<Vec<Src>, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Box<[Src]>) -> Vec<Dst>
+                where
+                    Neglect: UnsafeVecCastOptions,
+                {
+                    CastFrom::<_, Neglect>::unsafe_cast_from(src.into_vec())
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `Vec<Src>` into a `Box<[Dst]>`
+            ///
+            /// </h2>
+            ///
+            /// Equivalent to the [`Vec` cast][CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>] followed by [`into_boxed_slice`][Vec::into_boxed_slice], which shrinks the allocation to fit (reallocating only if the vec has excess capacity).
+            ///
+            /// This is the natural terminal step of pipelines that parse into an exact-size, immutable buffer; e.g.:
+            /// ```rust
+            /// let mut buf: Vec<[u8; 4]> = Vec::with_capacity(1024);
+            /// reader.read_records(&mut buf)?;
+            /// let samples: Box<[u32]> = buf.cast_into();
+            /// ```
+            impl<Src, Dst, Neglect> CastFrom<Vec<Src>, Neglect> for Box<[Dst]>
+            where
+                Neglect: UnsafeVecCastOptions,
+                Vec<Dst>: CastFrom<Vec<Src>, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Vec<Src>) -> Box<[Dst]>
+                where
+                    Neglect: UnsafeVecCastOptions,
+                {
+                    let dst: Vec<Dst> = CastFrom::<_, Neglect>::unsafe_cast_from(src);
+                    dst.into_boxed_slice()
+                }
+            }
+
+            use std::collections::VecDeque;
+
+            /// <h2>
+            ///
+            /// Cast a `VecDeque<Src>` into a `VecDeque<Dst>`
+            ///
+            /// </h2>
+            ///
+            /// The contents of a `VecDeque` may wrap around the end of its buffer. This cast first calls [`make_contiguous`][VecDeque::make_contiguous], then reuses the buffer via the [`Vec` cast][CastFrom#impl-CastFrom<Vec<Src>%2C%20Neglect>-for-Vec<Dst>]; the same size and alignment requirements apply.
+            impl<Src, Dst, Neglect> CastFrom<VecDeque<Src>, Neglect> for VecDeque<Dst>
+            where
+                Neglect: UnsafeVecCastOptions,
+                Vec<Dst>: CastFrom<Vec<Src>, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(mut src: VecDeque<Src>) -> VecDeque<Dst>
+                where
+                    Neglect: UnsafeVecCastOptions,
+                {
+                    src.make_contiguous();
+                    let src: Vec<Src> = src.into();
+                    let dst: Vec<Dst> = CastFrom::<_, Neglect>::unsafe_cast_from(src);
+                    dst.into()
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `&VecDeque<Src>` into a pair of slices `(&[Dst], &[Dst])`
+            ///
+            /// </h2>
+            ///
+            /// The zero-copy alternative to the above: rather than rearranging the deque, the two slices produced by [`as_slices`][VecDeque::as_slices] are each cast separately. Either slice may be empty.
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i VecDeque<Src>, Neglect> for (&'o [Dst], &'o [Dst])
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o [Dst]: CastFrom<&'i [Src], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i VecDeque<Src>) -> (&'o [Dst], &'o [Dst])
+                where
+                    Neglect: UnsafeSliceCastOptions,
+                {
+                    let (front, back) = src.as_slices();
+                    (
+                        CastFrom::<_, Neglect>::unsafe_cast_from(front),
+                        CastFrom::<_, Neglect>::unsafe_cast_from(back),
+                    )
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `&mut VecDeque<Src>` into a pair of slices `(&mut [Dst], &mut [Dst])`
+            ///
+            /// </h2>
+            ///
+            /// The mutable counterpart of the above, built on [`as_mut_slices`][VecDeque::as_mut_slices].
+            impl<'i, 'o, Src, Dst, Neglect> CastFrom<&'i mut VecDeque<Src>, Neglect> for (&'o mut [Dst], &'o mut [Dst])
+            where
+                Neglect: UnsafeSliceCastOptions,
+                &'o mut [Dst]: CastFrom<&'i mut [Src], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut VecDeque<Src>) -> (&'o mut [Dst], &'o mut [Dst])
+                where
+                    Neglect: UnsafeSliceCastOptions,
+                {
+                    let (front, back) = src.as_mut_slices();
+                    (
+                        CastFrom::<_, Neglect>::unsafe_cast_from(front),
+                        CastFrom::<_, Neglect>::unsafe_cast_from(back),
+                    )
+                }
+            }
+        }
+
+        // Options for casting the contents of arrays.
+        mod array {
+            use super::{
+                SafeCastOptions,
+                UnsafeCastOptions,
+                super::CastFrom,
+                super::super::transmute::{
+                    TransmuteFrom,
+                    options::{SafeTransmuteOptions, UnsafeTransmuteOptions},
+                },
+            };
+
+            use crate::mem::SizeEq;
+
+            /// Safe options for casting **arrays**.
+            ///
+            /// Array casting transmutes the contents of the array, and adjusts the array's length as needed. All [SafeTransmuteOptions] are [SafeArrayCastOptions].
+            pub trait SafeArrayCastOptions
+                : SafeCastOptions
+                + SafeTransmuteOptions
+                + UnsafeArrayCastOptions
+            {}
+
+            /// Unsafe options for casting **arrays**.
+            ///
+            /// Array casting transmutes the contents of the array, and adjusts the array's length as needed. All [UnsafeTransmuteOptions] are [UnsafeArrayCastOptions].
+            pub trait UnsafeArrayCastOptions
+                : UnsafeCastOptions
+                + UnsafeTransmuteOptions
+            {}
+
+            impl<Neglect: SafeTransmuteOptions> SafeArrayCastOptions for Neglect {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeArrayCastOptions for Neglect {}
+
+            /// <h2>
+            ///
+            /// Cast an array `[Src; N]` into an array `[Dst; M]`
+            ///
+            /// </h2>
+            ///
+            /// A value transmutation may shrink its source, so [TransmuteFrom] alone would permit silently discarding trailing elements. The [SizeEq] gadget additionally requires that `N * size_of::<Src>() == M * size_of::<Dst>()`; e.g.:
+            /// ```rust
+            /// let words: [u32; 2] = [0u8; 8].cast_into();
+            /// ```
+            ///
+            /// Nested arrays may be flattened and chunked the same way:
+            /// ```rust
+            /// let matrix: [[f32; 3]; 4] = Default::default();
+            /// let flat: [f32; 12] = matrix.cast_into();
+            /// let rows: [[f32; 4]; 3] = flat.cast_into();
+            /// ```
+            impl<Src, Dst, Neglect, const N: usize, const M: usize> CastFrom<[Src; N], Neglect> for [Dst; M]
+            where
+                Neglect: UnsafeArrayCastOptions,
+                [Dst; M]: TransmuteFrom<[Src; N], Neglect>
+                        + SizeEq<[Src; N], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: [Src; N]) -> [Dst; M]
+                where
+                    Neglect: UnsafeArrayCastOptions,
+                {
+                    TransmuteFrom::<_, Neglect>::unsafe_transmute_from(src)
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast an array reference `&[Src; N]` into an array reference `&[Dst; M]`
+            ///
+            /// </h2>
+            ///
+            /// As with owned arrays, [SizeEq] requires that `N * size_of::<Src>() == M * size_of::<Dst>()`. This flattens and chunks nested arrays in place:
+            /// ```rust
+            /// let image: &[[u8; 4]; 64] = &[[0; 4]; 64];
+            /// let bytes: &[u8; 256] = image.cast_into();
+            /// let pixels: &[[u8; 4]; 64] = bytes.cast_into();
+            /// ```
+            impl<'i, 'o, Src, Dst, Neglect, const N: usize, const M: usize> CastFrom<&'i [Src; N], Neglect> for &'o [Dst; M]
+            where
+                Neglect: UnsafeArrayCastOptions,
+                &'o [Dst; M]: TransmuteFrom<&'i [Src; N], Neglect>,
+                [Dst; M]: SizeEq<[Src; N], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i [Src; N]) -> &'o [Dst; M]
+                where
+                    Neglect: UnsafeArrayCastOptions,
+                {
+                    TransmuteFrom::<_, Neglect>::unsafe_transmute_from(src)
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast an array reference `&mut [Src; N]` into an array reference `&mut [Dst; M]`
+            ///
+            /// </h2>
+            ///
+            /// The mutable counterpart of the above.
+            impl<'i, 'o, Src, Dst, Neglect, const N: usize, const M: usize> CastFrom<&'i mut [Src; N], Neglect> for &'o mut [Dst; M]
+            where
+                Neglect: UnsafeArrayCastOptions,
+                &'o mut [Dst; M]: TransmuteFrom<&'i mut [Src; N], Neglect>,
+                [Dst; M]: SizeEq<[Src; N], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut [Src; N]) -> &'o mut [Dst; M]
+                where
+                    Neglect: UnsafeArrayCastOptions,
+                {
+                    TransmuteFrom::<_, Neglect>::unsafe_transmute_from(src)
+                }
+            }
+        }
+
+        // Options for casting the contents of containers implementing `ContainerCast`.
+        mod container {
+            use super::{
+                SafeCastOptions,
+                UnsafeCastOptions,
+                super::super::transmute::options::{SafeTransmuteOptions, UnsafeTransmuteOptions},
+            };
+
+            /// Safe options for casting **containers** implementing [ContainerCast][crate::cast::ContainerCast].
+            ///
+            /// Container casting transmutes the contents of the container, reusing its storage. All [SafeTransmuteOptions] are [SafeContainerCastOptions].
+            pub trait SafeContainerCastOptions
+                : SafeCastOptions
+                + SafeTransmuteOptions
+                + UnsafeContainerCastOptions
+            {}
+
+            /// Unsafe options for casting **containers** implementing [ContainerCast][crate::cast::ContainerCast].
+            ///
+            /// Container casting transmutes the contents of the container, reusing its storage. All [UnsafeTransmuteOptions] are [UnsafeContainerCastOptions].
+            pub trait UnsafeContainerCastOptions
+                : UnsafeCastOptions
+                + UnsafeTransmuteOptions
+            {}
+
+            impl<Neglect: SafeTransmuteOptions> SafeContainerCastOptions for Neglect {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeContainerCastOptions for Neglect {}
+        }
+
+        // Options for casting the contents of boxes.
+        mod boxed {
+            use super::{
+                SafeCastOptions,
+                UnsafeCastOptions,
+                super::{CastFrom, ContainerCast},
+                super::super::transmute::options::{SafeTransmuteOptions, UnsafeTransmuteOptions},
+            };
+
+            /// Safe options for casting **Box**.
+            ///
+            /// Box casting transmutes the contents of the box, reusing its allocation. All [SafeTransmuteOptions] are [SafeBoxCastOptions].
+            pub trait SafeBoxCastOptions
+                : SafeCastOptions
+                + SafeTransmuteOptions
+                + UnsafeBoxCastOptions
+            {}
+
+            /// Unsafe options for casting **Box**.
+            ///
+            /// Box casting transmutes the contents of the box, reusing its allocation. All [UnsafeTransmuteOptions] are [UnsafeBoxCastOptions].
+            pub trait UnsafeBoxCastOptions
+                : UnsafeCastOptions
+                + UnsafeTransmuteOptions
+            {}
+
+            impl<Neglect: SafeTransmuteOptions> SafeBoxCastOptions for Neglect {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeBoxCastOptions for Neglect {}
+
+            /// <h2>
+            ///
+            /// Cast a `Box<Src>` into a `Box<Dst>`
+            ///
+            /// </h2>
+            ///
+            /// [`Box::from_raw`][Box::from_raw] requires that the allocation is deallocated with the layout it was allocated with, so the size and alignment of `Src` and `Dst` must be equal. The [`ContainerCast` cast][crate::cast::impl_container_cast] enforces these invariants statically.
+            ///
+            /// This cast expresses the "allocate uninitialized, fill, then claim" pattern:
+            /// ```rust
+            /// let mut table: Box<MaybeUninit<Table>> = Box::new(MaybeUninit::uninit());
+            /// initialize(&mut table);
+            /// // Safe because `initialize` fully initialized the table.
+            /// let table: Box<Table> = unsafe { CastFrom::<_, NeglectValidity>::unsafe_cast_from(table) };
+            /// ```
+            /// The reverse direction, `Box<T>` into `Box<MaybeUninit<T>>`, is safe.
+            unsafe impl<Src, Dst> ContainerCast<Box<Dst>> for Box<Src>
+            {
+                type SrcElement = Src;
+                type DstElement = Dst;
+
+                #[inline(always)]
+                unsafe fn cast_elements(self) -> Box<Dst>
+                {
+                    Box::from_raw(Box::into_raw(self).cast::<Dst>())
+                }
+            }
+
+            crate::cast::impl_container_cast!([Src, Dst] Box<Src> => Box<Dst>);
+
+            use core::pin::Pin;
+
+            /// <h2>
+            ///
+            /// Cast a `Pin<Box<Src>>` into a `Pin<Box<Dst>>`
+            ///
+            /// </h2>
+            ///
+            /// In addition to the requirements of the [`Box` cast][ContainerCast#impl-ContainerCast<Box<Dst>>-for-Box<Src>], casting a pinned box must not violate the pinning guarantees of `Src`. Were `Src` reinterpreted as a `Dst: Unpin`, its memory could be moved out of the box, and the drop guarantee of `Src` broken. This cast therefore requires that `Src: Unpin`; i.e., that `Src` makes no pinning guarantees at all.
+            ///
+            /// To cast pinned boxes of `!Unpin` types whose pin projections are known to match, see [cast_pin_box_unchecked][crate::cast::cast_pin_box_unchecked].
+            impl<Src, Dst, Neglect> CastFrom<Pin<Box<Src>>, Neglect> for Pin<Box<Dst>>
+            where
+                Neglect: UnsafeBoxCastOptions,
+                Src: Unpin,
+                Box<Dst>: CastFrom<Box<Src>, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Pin<Box<Src>>) -> Pin<Box<Dst>>
+                where
+                    Neglect: UnsafeBoxCastOptions,
+                {
+                    let dst: Box<Dst> = CastFrom::<_, Neglect>::unsafe_cast_from(Pin::into_inner(src));
+                    Pin::from(dst)
+                }
+            }
+        }
+
+        // Casts exploiting the guaranteed null-pointer niche of `Option`.
+        mod niche {
+            use super::{
+                UnsafeCastOptions,
+                super::CastFrom,
+                super::super::transmute::options::NeglectValidity,
+            };
+
+            use core::ptr::{self, NonNull};
+
+            /// <h2>
+            ///
+            /// Cast an `Option<Box<T>>` into a `*mut T`
+            ///
+            /// </h2>
+            ///
+            /// `None` is cast into the null pointer; `Some(b)` is cast into [`Box::into_raw(b)`][Box::into_raw].
+            impl<T, Neglect> CastFrom<Option<Box<T>>, Neglect> for *mut T
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Option<Box<T>>) -> *mut T
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    src.map_or(ptr::null_mut(), Box::into_raw)
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `*mut T` into an `Option<Box<T>>`
+            ///
+            /// </h2>
+            ///
+            /// Not every pointer is a valid `Box`, so this cast is only available with [NeglectValidity]. The null pointer is cast into `None`.
+            ///
+            /// By using this cast, you are committing to ensure that a non-null source pointer satisfies the requirements of [`Box::from_raw`][Box::from_raw].
+            impl<T> CastFrom<*mut T, NeglectValidity> for Option<Box<T>>
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: *mut T) -> Option<Box<T>>
+                where
+                    NeglectValidity: UnsafeCastOptions,
+                {
+                    NonNull::new(src).map(|src| unsafe { Box::from_raw(src.as_ptr()) })
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast an `Option<&T>` into a `*const T`
+            ///
+            /// </h2>
+            ///
+            /// `None` is cast into the null pointer.
+            impl<'a, T, Neglect> CastFrom<Option<&'a T>, Neglect> for *const T
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Option<&'a T>) -> *const T
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    src.map_or(ptr::null(), |src| src)
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `*const T` into an `Option<&T>`
+            ///
+            /// </h2>
+            ///
+            /// Not every pointer is a valid reference, so this cast is only available with [NeglectValidity]. The null pointer is cast into `None`.
+            ///
+            /// By using this cast, you are committing to ensure that a non-null source pointer is aligned, dereferenceable, and valid for the lifetime `'a`.
+            impl<'a, T> CastFrom<*const T, NeglectValidity> for Option<&'a T>
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: *const T) -> Option<&'a T>
+                where
+                    NeglectValidity: UnsafeCastOptions,
+                {
+                    unsafe { src.as_ref() }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast an `Option<NonNull<T>>` into a `*mut T`
+            ///
+            /// </h2>
+            ///
+            /// `None` is cast into the null pointer.
+            impl<T, Neglect> CastFrom<Option<NonNull<T>>, Neglect> for *mut T
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Option<NonNull<T>>) -> *mut T
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    src.map_or(ptr::null_mut(), NonNull::as_ptr)
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `*mut T` into an `Option<NonNull<T>>`
+            ///
+            /// </h2>
+            ///
+            /// Every pointer is either null or non-null, so this cast is safe. The null pointer is cast into `None`.
+            impl<T, Neglect> CastFrom<*mut T, Neglect> for Option<NonNull<T>>
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: *mut T) -> Option<NonNull<T>>
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    NonNull::new(src)
+                }
+            }
+        }
+
+        // Casts between references and raw pointers.
+        mod pointer {
+            use super::{
+                UnsafeCastOptions,
+                super::CastFrom,
+                super::super::transmute::{TransmuteFrom, options::UnsafeTransmuteOptions},
+            };
+
+            use core::marker::PhantomData;
+
+            /// Neglect the static guarantee that a reference is non-null, aligned, dereferenceable and live, permitting raw pointers to be cast into references.
+            ///
+            /// The layouts of the referents are still checked statically, with the transmutation options `Neglect`. This option is never safe; by using it, you are committing to ensure that the source pointer satisfies the requirements of a reference to its referent, for the lifetime of the resulting reference; e.g.:
+            /// ```rust
+            /// // Safe because `ptr` was produced from a live `&Header`.
+            /// let header: &Header = unsafe { CastFrom::<_, NeglectDereferenceability>::unsafe_cast_from(ptr) };
+            /// ```
+            pub struct NeglectDereferenceability<Neglect = ()>(PhantomData<Neglect>);
+
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeCastOptions for NeglectDereferenceability<Neglect> {}
+
+            /// <h2>
+            ///
+            /// Cast a `&Src` into a `*const Dst`
+            ///
+            /// </h2>
+            ///
+            /// A reference may always be demoted to a raw pointer. Its referent may also be reinterpreted, subject to the same static checks as transmuting a `&Src` into a `&Dst`, with the transmutation options `Neglect`; the resulting pointer is thereby known to be aligned and dereferenceable for a `Dst`.
+            impl<'a, Src, Dst: 'a, Neglect> CastFrom<&'a Src, Neglect> for *const Dst
+            where
+                Neglect: UnsafeTransmuteOptions,
+                &'a Dst: TransmuteFrom<&'a Src, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a Src) -> *const Dst
+                where
+                    Neglect: UnsafeTransmuteOptions,
+                {
+                    (src as *const Src).cast::<Dst>()
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `&mut Src` into a `*mut Dst`
+            ///
+            /// </h2>
+            ///
+            /// As with the [shared reference cast][CastFrom#impl-CastFrom<%26%27a%20Src%2C%20Neglect>-for-*const%20Dst], subject to the static checks of transmuting a `&mut Src` into a `&mut Dst`.
+            impl<'a, Src, Dst: 'a, Neglect> CastFrom<&'a mut Src, Neglect> for *mut Dst
+            where
+                Neglect: UnsafeTransmuteOptions,
+                &'a mut Dst: TransmuteFrom<&'a mut Src, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a mut Src) -> *mut Dst
+                where
+                    Neglect: UnsafeTransmuteOptions,
+                {
+                    (src as *mut Src).cast::<Dst>()
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `*const Src` into a `&Dst`
+            ///
+            /// </h2>
+            ///
+            /// Not every pointer is a valid reference, so this cast is only available with [NeglectDereferenceability]. The layouts of `Src` and `Dst` are still checked statically, as when transmuting a `&Src` into a `&Dst` with the options `Neglect`.
+            ///
+            /// By using this cast, you are committing to ensure that the source pointer is non-null, aligned, and dereferenceable for a `Src` that is valid for the lifetime `'a`.
+            impl<'a, Src: 'a, Dst: 'a, Neglect> CastFrom<*const Src, NeglectDereferenceability<Neglect>> for &'a Dst
+            where
+                Neglect: UnsafeTransmuteOptions,
+                &'a Dst: TransmuteFrom<&'a Src, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: *const Src) -> &'a Dst
+                where
+                    NeglectDereferenceability<Neglect>: UnsafeCastOptions,
+                {
+                    TransmuteFrom::<_, Neglect>::unsafe_transmute_from(&*src)
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `*mut Src` into a `&mut Dst`
+            ///
+            /// </h2>
+            ///
+            /// As with the [shared reference cast][CastFrom#impl-CastFrom<*const%20Src%2C%20NeglectDereferenceability<Neglect>>-for-%26%27a%20Dst]. By using this cast, you are additionally committing to ensure that the referent is not otherwise accessed for the lifetime `'a`.
+            impl<'a, Src: 'a, Dst: 'a, Neglect> CastFrom<*mut Src, NeglectDereferenceability<Neglect>> for &'a mut Dst
+            where
+                Neglect: UnsafeTransmuteOptions,
+                &'a mut Dst: TransmuteFrom<&'a mut Src, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: *mut Src) -> &'a mut Dst
+                where
+                    NeglectDereferenceability<Neglect>: UnsafeCastOptions,
+                {
+                    TransmuteFrom::<_, Neglect>::unsafe_transmute_from(&mut *src)
+                }
+            }
+        }
+
+        // Casts between reference-counted strings and byte slices.
+        mod shared {
+            use super::{
+                UnsafeCastOptions,
+                super::CastFrom,
+            };
+
+            use std::{rc::Rc, sync::Arc};
+
+            /// <h2>
+            ///
+            /// Cast an `Arc<str>` into an `Arc<[u8]>`
+            ///
+            /// </h2>
+            ///
+            /// Every `str` is a valid `[u8]`, so this cast reuses the allocation. The reverse direction requires validation; see [try_cast_arc_str][crate::cast::try_cast_arc_str].
+            impl<Neglect> CastFrom<Arc<str>, Neglect> for Arc<[u8]>
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Arc<str>) -> Arc<[u8]>
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    unsafe { Arc::from_raw(Arc::into_raw(src) as *const [u8]) }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast an `Rc<str>` into an `Rc<[u8]>`
+            ///
+            /// </h2>
+            ///
+            /// Every `str` is a valid `[u8]`, so this cast reuses the allocation. The reverse direction requires validation; see [try_cast_rc_str][crate::cast::try_cast_rc_str].
+            impl<Neglect> CastFrom<Rc<str>, Neglect> for Rc<[u8]>
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: Rc<str>) -> Rc<[u8]>
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    unsafe { Rc::from_raw(Rc::into_raw(src) as *const [u8]) }
+                }
+            }
+        }
+
+        /// Options for casting mutable references into mutable references to a prefix of their referents.
+        mod prefix {
+            use super::{
+                SafeCastOptions,
+                UnsafeCastOptions,
+                super::CastFrom,
+                super::super::transmute::options::{SafeTransmuteOptions, UnsafeTransmuteOptions},
+            };
+
+            use core::marker::PhantomData;
+
+            use crate::mem::{AlignLtEq, HasPrefix};
+
+            /// Permit casting a mutable reference into a mutable reference to a prefix of its referent, which is followed by a `Tail`.
+            ///
+            /// Transmuting `&mut Src` into `&mut Dst` ordinarily requires `Src` and `Dst` to be mutually transmutable, since any `Dst` written through the resulting reference must leave behind a valid `Src`. If `Dst` is smaller than `Src`, this is impossible. However, if `Src` is laid out as a `Dst` followed by a `Tail`, then writing any `Dst` to its prefix leaves behind a valid `Src`, with the transmutation options `Neglect`. This makes in-place editing of headers expressible (for shared references, see [prefix_of][super::prefix_of]):
+            /// ```rust
+            /// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+            /// #[repr(C)]
+            /// pub struct Packet { pub header: Header, pub body: [u8; 1024] }
+            ///
+            /// let header: &mut Header = CastFrom::<_, PermitPrefix<[u8; 1024]>>::cast_from(&mut packet);
+            /// header.len = 512;
+            /// ```
+            pub struct PermitPrefix<Tail, Neglect = ()>(PhantomData<(Tail, Neglect)>);
+
+            impl<Tail, Neglect: SafeTransmuteOptions> SafeCastOptions for PermitPrefix<Tail, Neglect> {}
+            impl<Tail, Neglect: UnsafeTransmuteOptions> UnsafeCastOptions for PermitPrefix<Tail, Neglect> {}
+
+            /// <h2>
+            ///
+            /// Cast a `&mut Src` into a `&mut Dst` referring to a prefix of `Src`
+            ///
+            /// </h2>
+            ///
+            /// The conditions under which overwriting the prefix of a `Src` with a `Dst` is sound are encoded by the [HasPrefix] gadget.
+            impl<'i, 'o, Src, Dst, Tail, Neglect> CastFrom<&'i mut Src, PermitPrefix<Tail, Neglect>> for &'o mut Dst
+            where
+                'i: 'o,
+                Neglect: UnsafeTransmuteOptions,
+                Src: HasPrefix<Dst, Tail, Neglect>,
+                Dst: AlignLtEq<Src, Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'i mut Src) -> &'o mut Dst
+                where
+                    Neglect: UnsafeTransmuteOptions,
+                {
+                    unsafe { &mut *(src as *mut Src).cast::<Dst>() }
+                }
+            }
+        }
+
+        // Casts between OS strings and bytes, on platforms where OS strings are bytes.
+        #[cfg(unix)]
+        mod os {
+            use super::{
+                UnsafeCastOptions,
+                super::CastFrom,
+            };
+
+            use std::{
+                ffi::OsStr,
+                os::unix::ffi::OsStrExt,
+                path::Path,
+            };
+
+            /// <h2>
+            ///
+            /// Cast an `&OsStr` into an `&[u8]`
+            ///
+            /// </h2>
+            ///
+            /// On Unix, an `OsStr` is an arbitrary sequence of bytes, as documented by [OsStrExt]. ***This cast is only available on Unix.***
+            impl<'a, Neglect> CastFrom<&'a OsStr, Neglect> for &'a [u8]
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a OsStr) -> &'a [u8]
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    src.as_bytes()
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast an `&[u8]` into an `&OsStr`
+            ///
+            /// </h2>
+            ///
+            /// On Unix, every sequence of bytes is a valid `OsStr`, as documented by [OsStrExt]. ***This cast is only available on Unix.***
+            impl<'a, Neglect> CastFrom<&'a [u8], Neglect> for &'a OsStr
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a [u8]) -> &'a OsStr
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    OsStr::from_bytes(src)
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast an `&Path` into an `&[u8]`
+            ///
+            /// </h2>
+            ///
+            /// A `Path` is a thin wrapper around an `OsStr`, so filesystem code may hash, compare, and serialize paths as bytes; e.g.:
+            /// ```rust
+            /// let key: &[u8] = path.cast_ref();
+            /// ```
+            /// ***This cast is only available on Unix.***
+            impl<'a, Neglect> CastFrom<&'a Path, Neglect> for &'a [u8]
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a Path) -> &'a [u8]
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    src.as_os_str().as_bytes()
+                }
+            }
+        }
+
+        /// Options for casting C strings into bytes.
+        mod cstr {
+            use super::{
+                SafeCastOptions,
+                UnsafeCastOptions,
+                super::CastFrom,
+                super::super::transmute::options::{SafeTransmuteOptions, UnsafeTransmuteOptions},
+            };
+
+            use core::marker::PhantomData;
+
+            use std::ffi::CStr;
+
+            /// Include the trailing nul byte when casting a `&CStr` into an `&[u8]`.
+            ///
+            /// By default, the cast excludes the nul:
+            /// ```rust
+            /// let name: &CStr = try_cast_cstr(b"eth0\0")?;
+            /// let bytes: &[u8] = name.cast_into();                               // b"eth0"
+            /// let bytes: &[u8] = CastFrom::<_, WithNul>::cast_from(name); // b"eth0\0"
+            /// ```
+            /// The reverse cast must check for interior nul bytes; see [try_cast_cstr][super::super::try_cast_cstr].
+            pub struct WithNul<Neglect = ()>(PhantomData<Neglect>);
+
+            impl<Neglect: SafeTransmuteOptions> SafeCastOptions for WithNul<Neglect> {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeCastOptions for WithNul<Neglect> {}
+
+            /// <h2>
+            ///
+            /// Cast a `&CStr` into an `&[u8]`, excluding its trailing nul
+            ///
+            /// </h2>
+            ///
+            /// Every `CStr` is a sequence of non-nul bytes, followed by a nul.
+            impl<'a, Neglect> CastFrom<&'a CStr, Neglect> for &'a [u8]
+            where
+                Neglect: UnsafeTransmuteOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a CStr) -> &'a [u8]
+                where
+                    Neglect: UnsafeTransmuteOptions,
+                {
+                    src.to_bytes()
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a `&CStr` into an `&[u8]`, including its trailing nul
+            ///
+            /// </h2>
+            impl<'a, Neglect> CastFrom<&'a CStr, WithNul<Neglect>> for &'a [u8]
+            where
+                Neglect: UnsafeTransmuteOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a CStr) -> &'a [u8]
+                where
+                    Neglect: UnsafeTransmuteOptions,
+                {
+                    src.to_bytes_with_nul()
+                }
+            }
+        }
+
+        /// Options for casting between atomics and plain integers.
+        mod atomic {
+            use super::{
+                SafeCastOptions,
+                UnsafeCastOptions,
+                super::CastFrom,
+                super::super::transmute::options::{SafeTransmuteOptions, UnsafeTransmuteOptions},
+            };
+
+            use core::{marker::PhantomData, sync::atomic::*};
+
+            use crate::mem::AlignLtEq;
+
+            /// Permit casts that introduce or remove interior mutability; namely, between references to atomics and plain integers of the same width, with the transmutation options `Neglect`.
+            ///
+            /// These casts generalize the standard library's `AtomicU32::from_mut` family over all widths, and over the transmutation options:
+            /// ```rust
+            /// let counter: &AtomicU32 = CastFrom::<_, PermitInteriorMutability>::cast_from(&mut count);
+            /// (0..8).into_par_iter().for_each(|_| { counter.fetch_add(1, Relaxed); });
+            /// ```
+            ///
+            /// Casts are only implemented from *uniquely* borrowed sources, which guarantees that, for the lifetime of the result, every access is either atomic or unique. Casting a shared `&AtomicU32` into a `&u32` is unsound, since other threads may concurrently write through other references to the atomic.
+            ///
+            /// On some targets, atomics are more aligned than the plain integers of the same width (e.g., `AtomicU64` and `u64` on 32-bit x86). Casts into references to such atomics are rejected unless alignment is neglected and checked dynamically.
+            pub struct PermitInteriorMutability<Neglect = ()>(PhantomData<Neglect>);
+
+            impl<Neglect: SafeTransmuteOptions> SafeCastOptions for PermitInteriorMutability<Neglect> {}
+            impl<Neglect: UnsafeTransmuteOptions> UnsafeCastOptions for PermitInteriorMutability<Neglect> {}
+
+            macro_rules! atomic_casts {
+                ($($atomic:ident($int:ty);)*) => {$(
+                    /// Cast a uniquely borrowed integer into a shared atomic. See [PermitInteriorMutability].
+                    impl<'a, Neglect> CastFrom<&'a mut $int, PermitInteriorMutability<Neglect>> for &'a $atomic
+                    where
+                        Neglect: UnsafeTransmuteOptions,
+                        $atomic: AlignLtEq<$int, Neglect>,
+                    {
+                        #[doc(hidden)]
+                        #[inline(always)]
+                        unsafe fn unsafe_cast_from(src: &'a mut $int) -> &'a $atomic {
+                            unsafe { &*(src as *mut $int).cast::<$atomic>() }
+                        }
+                    }
+
+                    /// Cast a uniquely borrowed integer into a uniquely borrowed atomic. See [PermitInteriorMutability].
+                    impl<'a, Neglect> CastFrom<&'a mut $int, PermitInteriorMutability<Neglect>> for &'a mut $atomic
+                    where
+                        Neglect: UnsafeTransmuteOptions,
+                        $atomic: AlignLtEq<$int, Neglect>,
+                    {
+                        #[doc(hidden)]
+                        #[inline(always)]
+                        unsafe fn unsafe_cast_from(src: &'a mut $int) -> &'a mut $atomic {
+                            unsafe { &mut *(src as *mut $int).cast::<$atomic>() }
+                        }
+                    }
+
+                    /// Cast a uniquely borrowed atomic into a shared integer. See [PermitInteriorMutability].
+                    impl<'a, Neglect> CastFrom<&'a mut $atomic, PermitInteriorMutability<Neglect>> for &'a $int
+                    where
+                        Neglect: UnsafeTransmuteOptions,
+                    {
+                        #[doc(hidden)]
+                        #[inline(always)]
+                        unsafe fn unsafe_cast_from(src: &'a mut $atomic) -> &'a $int {
+                            src.get_mut()
+                        }
+                    }
+
+                    /// Cast a uniquely borrowed atomic into a uniquely borrowed integer. See [PermitInteriorMutability].
+                    impl<'a, Neglect> CastFrom<&'a mut $atomic, PermitInteriorMutability<Neglect>> for &'a mut $int
+                    where
+                        Neglect: UnsafeTransmuteOptions,
+                    {
+                        #[doc(hidden)]
+                        #[inline(always)]
+                        unsafe fn unsafe_cast_from(src: &'a mut $atomic) -> &'a mut $int {
+                            src.get_mut()
+                        }
+                    }
+                )*}
+            }
+
+            atomic_casts! {
+                AtomicBool(bool);
+                AtomicI8(i8);
+                AtomicI16(i16);
+                AtomicI32(i32);
+                AtomicI64(i64);
+                AtomicIsize(isize);
+                AtomicU8(u8);
+                AtomicU16(u16);
+                AtomicU32(u32);
+                AtomicU64(u64);
+                AtomicUsize(usize);
+            }
+        }
+
+        // Casts between cells of slices and slices of cells.
+        mod cell {
+            use super::{
+                UnsafeCastOptions,
+                slice::UnsafeSliceCastOptions,
+                super::CastFrom,
+            };
+
+            use core::{cell::Cell, mem::size_of_val, slice};
+
+            /// <h2>
+            ///
+            /// Cast a slice of cells `&[Cell<T>]` into a cell of a slice `&Cell<[T]>`
+            ///
+            /// </h2>
+            ///
+            /// `Cell<T>` has the same in-memory representation as `T`, so this is the reciprocal of [`Cell::as_slice_of_cells`].
+            impl<'a, T, Neglect> CastFrom<&'a [Cell<T>], Neglect> for &'a Cell<[T]>
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a [Cell<T>]) -> &'a Cell<[T]>
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    unsafe { &*(src as *const [Cell<T>] as *const Cell<[T]>) }
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a cell of a slice `&Cell<[T]>` into a slice of cells `&[Cell<T>]`
+            ///
+            /// </h2>
+            ///
+            /// Equivalent to [`Cell::as_slice_of_cells`].
+            impl<'a, T, Neglect> CastFrom<&'a Cell<[T]>, Neglect> for &'a [Cell<T>]
+            where
+                Neglect: UnsafeCastOptions,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a Cell<[T]>) -> &'a [Cell<T>]
+                where
+                    Neglect: UnsafeCastOptions,
+                {
+                    src.as_slice_of_cells()
+                }
+            }
+
+            /// <h2>
+            ///
+            /// Cast a cell `&Cell<T>` into a slice of byte cells `&[Cell<u8>]`
+            ///
+            /// </h2>
+            ///
+            /// Each byte of `T` may be read *and* written through the resulting slice, so `T` must be mutually transmutable with its bytes. This is precisely the requirement of casting `&mut [T]` into `&mut [u8]`.
+            impl<'a, T, Neglect> CastFrom<&'a Cell<T>, Neglect> for &'a [Cell<u8>]
+            where
+                Neglect: UnsafeSliceCastOptions,
+                for<'b> &'b mut [u8]: CastFrom<&'b mut [T], Neglect>,
+            {
+                #[doc(hidden)]
+                #[inline(always)]
+                unsafe fn unsafe_cast_from(src: &'a Cell<T>) -> &'a [Cell<u8>]
+                where
+                    Neglect: UnsafeSliceCastOptions,
+                {
+                    let len = size_of_val(src);
+                    unsafe { slice::from_raw_parts(src.as_ptr().cast::<Cell<u8>>(), len) }
+                }
+            }
+        }
+
+    }
+}
+
+/// (Extension) Transmuting iterator adapters.
+///
+/// This module demonstrates how the [transmute] API may be used to change the representation of items in streaming pipelines, without first collecting them into an intermediate `Vec`.
+#[unstable(feature = "transmute_iter", issue = "none")]
+pub mod iter {
+    use crate::transmute::{TransmuteFrom, options::*};
+    use core::{iter::FusedIterator, marker::PhantomData};
+
+    /// Extension methods for transmuting the items of iterators.
+    pub trait IteratorExt: Iterator + Sized {
+        /// Lazily transmute each item of `self` into `Dst`, safely.
+        ///
+        /// ```rust
+        /// let bits: Vec<u32> = floats.into_iter().transmute_items::<u32>().collect();
+        /// ```
+        /// To transmute with options other than `()`, use [TransmuteItems::new].
+        #[inline(always)]
+        fn transmute_items<Dst>(self) -> TransmuteItems<Self, Dst>
+        where
+            Dst: TransmuteFrom<Self::Item>,
+        {
+            TransmuteItems::new(self)
+        }
+    }
+
+    impl<I: Iterator> IteratorExt for I {}
+
+    /// An iterator that transmutes the items of `I` into `Dst`.
+    ///
+    /// This `struct` is created by [IteratorExt::transmute_items].
+    pub struct TransmuteItems<I, Dst, Neglect = ()> {
+        iter: I,
+        _marker: PhantomData<fn() -> (Dst, Neglect)>,
+    }
+
+    impl<I, Dst, Neglect> TransmuteItems<I, Dst, Neglect>
+    where
+        I: Iterator,
+        Dst: TransmuteFrom<I::Item, Neglect>,
+        Neglect: SafeTransmuteOptions,
+    {
+        /// Lazily transmute each item of `iter` into `Dst`, with the options `Neglect`.
+        #[inline(always)]
+        pub fn new(iter: I) -> Self {
+            Self { iter, _marker: PhantomData }
+        }
+    }
+
+    impl<I, Dst, Neglect> Iterator for TransmuteItems<I, Dst, Neglect>
+    where
+        I: Iterator,
+        Dst: TransmuteFrom<I::Item, Neglect>,
+        Neglect: SafeTransmuteOptions,
+    {
+        type Item = Dst;
+
+        #[inline(always)]
+        fn next(&mut self) -> Option<Dst> {
+            self.iter.next().map(TransmuteFrom::<_, Neglect>::transmute_from)
+        }
+
+        #[inline(always)]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.iter.size_hint()
+        }
+    }
+
+    impl<I, Dst, Neglect> DoubleEndedIterator for TransmuteItems<I, Dst, Neglect>
+    where
+        I: DoubleEndedIterator,
+        Dst: TransmuteFrom<I::Item, Neglect>,
+        Neglect: SafeTransmuteOptions,
+    {
+        #[inline(always)]
+        fn next_back(&mut self) -> Option<Dst> {
+            self.iter.next_back().map(TransmuteFrom::<_, Neglect>::transmute_from)
+        }
+    }
+
+    impl<I, Dst, Neglect> ExactSizeIterator for TransmuteItems<I, Dst, Neglect>
+    where
+        I: ExactSizeIterator,
+        Dst: TransmuteFrom<I::Item, Neglect>,
+        Neglect: SafeTransmuteOptions,
+    {}
+
+    impl<I, Dst, Neglect> FusedIterator for TransmuteItems<I, Dst, Neglect>
+    where
+        I: FusedIterator,
+        Dst: TransmuteFrom<I::Item, Neglect>,
+        Neglect: SafeTransmuteOptions,
+    {}
+}
+
+/// (Extension) Runtime validity checks.
+///
+/// The [NeglectValidity][transmute::options::NeglectValidity] option permits transmutations which *might* be valid depending on the source value, provided that you dynamically ensure that they are. This module packages that pattern: the bit validity of any type implementing [CheckBitValidity] can be checked at runtime, before transmuting.
+#[unstable(feature = "validity", issue = "none")]
+pub mod validity {
+    use core::{fmt, mem::size_of};
+
+    /// Types whose bit validity can be checked at runtime.
+    ///
+    /// `#[derive(PromiseTransmutableFrom)]` generates an implementation of this trait alongside the type's archetype. The generated [locate_invalid][CheckBitValidity::locate_invalid] delegates to the fields' implementations, so that errors name the innermost invalid field; e.g., `header.opcode`.
+    pub unsafe trait CheckBitValidity {
+        /// Produces `true` if `bytes` is a bit-valid instance of `Self`.
+        ///
+        /// The length of `bytes` is always `size_of::<Self>()`.
+        fn is_bit_valid(bytes: &[u8]) -> bool;
+
+        /// Produces `true` if `bytes` consists of bit-valid instances of `Self`.
+        ///
+        /// The length of `bytes` is always a multiple of `size_of::<Self>()`. By default, each instance is checked in turn with [is_bit_valid][CheckBitValidity::is_bit_valid]; implementations may override this with a vectorized check. `#[derive(PromiseTransmutableFrom)]` does so for field-less `#[repr(u8)]` enums whose discriminants are `0..N`.
+        fn is_slice_bit_valid(bytes: &[u8]) -> bool
+        where
+            Self: Sized,
+        {
+            bytes.chunks_exact(size_of::<Self>()).all(Self::is_bit_valid)
+        }
+
+        /// Locates the invalid field of `bytes`, if `bytes` is not a bit-valid instance of `Self`.
+        ///
+        /// The length of `bytes` is always `size_of::<Self>()`. By default, `Self` as a whole is reported as invalid.
+        fn locate_invalid(bytes: &[u8]) -> Option<InvalidField> {
+            match Self::is_bit_valid(bytes) {
+                true => None,
+                false => Some(InvalidField { offset: 0, path: FieldPath::root(), expected: core::any::type_name::<Self>() }),
+            }
+        }
+    }
+
+    /// The location of an invalid field, as reported by [CheckBitValidity::locate_invalid].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct InvalidField {
+        /// The byte offset of the field, relative to the checked bytes.
+        pub offset: usize,
+        /// The path of the field; empty if the checked type as a whole is invalid.
+        pub path: FieldPath,
+        /// The name of the field's type, whose validity the bytes violate.
+        pub expected: &'static str,
+    }
+
+    /// The path of a field, as a list of field names from the outermost inward.
+    ///
+    /// Validators of types with fields compose the paths reported by their fields' validators, by nesting them [within][FieldPath::within] the name of the field; e.g.:
+    /// ```rust
+    /// fn locate_invalid(bytes: &[u8]) -> Option<InvalidField> {
+    ///     Header::locate_invalid(&bytes[..size_of::<Header>()])
+    ///         .map(|field| InvalidField { path: field.path.within("header"), ..field })
+    /// }
+    /// ```
+    /// Paths are displayed as `.`-separated lists of field names; e.g., `header.opcode`. A path records at most [MAX_DEPTH][FieldPath::MAX_DEPTH] names; nesting a full path discards its innermost name.
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct FieldPath {
+        segments: [&'static str; FieldPath::MAX_DEPTH],
+        len: usize,
+    }
+
+    impl FieldPath {
+        /// The maximum number of field names recorded by a path.
+        pub const MAX_DEPTH: usize = 8;
+
+        /// The empty path, denoting the checked type as a whole.
+        pub const fn root() -> Self {
+            FieldPath { segments: [""; FieldPath::MAX_DEPTH], len: 0 }
+        }
+
+        /// The path to the field `name` of the checked type.
+        pub const fn field(name: &'static str) -> Self {
+            Self::root().within(name)
+        }
+
+        /// This path, relative to the field `name` of an enclosing type.
+        pub const fn within(self, name: &'static str) -> Self {
+            let mut segments = [""; FieldPath::MAX_DEPTH];
+            segments[0] = name;
+            let len = if self.len < FieldPath::MAX_DEPTH { self.len + 1 } else { FieldPath::MAX_DEPTH };
+            let mut i = 1;
+            while i < len {
+                segments[i] = self.segments[i - 1];
+                i += 1;
+            }
+            FieldPath { segments, len }
+        }
+
+        /// Produces `true` if this is the [root][FieldPath::root] path.
+        pub const fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// The field names of this path, from the outermost inward.
+        pub fn segments(&self) -> &[&'static str] {
+            &self.segments[..self.len]
+        }
+    }
+
+    impl fmt::Display for FieldPath {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (i, segment) in self.segments().iter().enumerate() {
+                if i > 0 {
+                    f.write_str(".")?;
+                }
+                f.write_str(segment)?;
+            }
+            Ok(())
+        }
+    }
+
+    impl fmt::Debug for FieldPath {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "\"{}\"", self)
+        }
+    }
+
+    /// The error produced when a runtime validity check fails.
+    ///
+    /// Where it is known, the error records *where* the bytes are invalid, which is essential for debugging corrupted inputs:
+    /// ```rust
+    /// let err = try_cast_slice::<u8, Packet>(bytes).unwrap_err();
+    /// eprintln!("{}", err); // invalid bytes at offset 42 (field `header.opcode`): expected a bit-valid `Opcode`
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ValidityError {
+        field: Option<InvalidField>,
+    }
+
+    impl ValidityError {
+        pub(crate) const fn new() -> Self {
+            ValidityError { field: None }
+        }
+
+        /// An error for the invalid `field` of a value beginning at byte `base`.
+        pub(crate) const fn at(base: usize, field: InvalidField) -> Self {
+            ValidityError { field: Some(InvalidField { offset: base + field.offset, ..field }) }
+        }
+
+        /// This error, for a value beginning `base` bytes further into the checked bytes.
+        pub(crate) fn offset_by(self, base: usize) -> Self {
+            match self.field {
+                Some(field) => ValidityError::at(base, field),
+                None => self,
+            }
+        }
+
+        /// The byte offset of the invalid field, if known.
+        pub fn offset(&self) -> Option<usize> {
+            self.field.map(|field| field.offset)
+        }
+
+        /// The path of the invalid field, if known; e.g., `header.opcode`.
+        pub fn field_path(&self) -> Option<FieldPath> {
+            self.field.map(|field| field.path).filter(|path| !path.is_empty())
+        }
+
+        /// The name of the type whose validity the bytes violate, if known.
+        pub fn expected(&self) -> Option<&'static str> {
+            self.field.map(|field| field.expected)
+        }
+    }
+
+    impl fmt::Display for ValidityError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self.field {
+                None => f.write_str("bytes are not a bit-valid instance of the destination type"),
+                Some(InvalidField { offset, path, expected }) if path.is_empty() =>
+                    write!(f, "invalid bytes at offset {}: expected a bit-valid `{}`", offset, expected),
+                Some(InvalidField { offset, path, expected }) =>
+                    write!(f, "invalid bytes at offset {} (field `{}`): expected a bit-valid `{}`", offset, path, expected),
+            }
+        }
+    }
+
+    /// Check that `bytes` is a bit-valid instance of `T`, locating the invalid field if not.
+    #[inline]
+    pub(crate) fn check<T: CheckBitValidity + ?Sized>(bytes: &[u8]) -> Result<(), ValidityError> {
+        match T::is_bit_valid(bytes) {
+            true => Ok(()),
+            false => Err(T::locate_invalid(bytes).map_or(ValidityError::new(), |field| ValidityError::at(0, field))),
+        }
+    }
+
+    impl std::error::Error for ValidityError {}
+
+    use crate::{
+        cast::CastFrom,
+        transmute::{TransmuteFrom, options::{NeglectAlignment, NeglectValidity}},
+    };
+
+    /// Transmute a value of type `Src` into `Dst`, checking the bit validity of the result at runtime.
+    ///
+    /// This is the checked counterpart of [NeglectValidity]: it permits transmutations, like `u8` to `bool` or `u32` to `char`, which are valid for only some source values; e.g.:
+    /// ```rust
+    /// let flag: bool = try_transmute(byte)?;
+    /// ```
+    #[inline]
+    pub fn try_transmute<Src, Dst>(src: Src) -> Result<Dst, ValidityError>
+    where
+        Dst: TransmuteFrom<Src, NeglectValidity> + CheckBitValidity,
+        for<'a> &'a [u8]: CastFrom<&'a [Src]>,
+    {
+        let bytes: &[u8] = CastFrom::cast_from(core::slice::from_ref(&src));
+        check::<Dst>(&bytes[..size_of::<Dst>()])?;
+        // Safe because we've dynamically verified that the bytes of `src`
+        // are a bit-valid instance of `Dst`, the static check for which we
+        // chose to neglect.
+        Ok(unsafe { Dst::unsafe_transmute_from(src) })
+    }
+
+    /// Transmute `src` into `Dst`, if the caller-supplied predicate accepts its bytes.
+    ///
+    /// This supports destination types that lack a [CheckBitValidity] implementation, such as foreign types; e.g.:
+    /// ```rust
+    /// // Safe because `ffi::Mode` is valid for exactly the values `0..=3`.
+    /// let mode: ffi::Mode = unsafe { unsafe_transmute_with(word, |b| u32::from_ne_bytes(b.try_into().unwrap()) <= 3)? };
+    /// ```
+    /// The predicate is given the leading `size_of::<Dst>()` bytes of `src`.
+    ///
+    /// # Safety
+    /// The predicate must only return `true` if its bytes are a bit-valid instance of `Dst`.
+    pub unsafe fn unsafe_transmute_with<Src, Dst, F>(src: Src, is_bit_valid: F) -> Result<Dst, ValidityError>
+    where
+        Dst: TransmuteFrom<Src, NeglectValidity>,
+        for<'a> &'a [u8]: CastFrom<&'a [Src]>,
+        F: FnOnce(&[u8]) -> bool,
+    {
+        let bytes: &[u8] = CastFrom::cast_from(core::slice::from_ref(&src));
+        match is_bit_valid(&bytes[..size_of::<Dst>()]) {
+            // Safe because the caller guarantees that the predicate only
+            // accepts bit-valid instances of `Dst`.
+            true => Ok(Dst::unsafe_transmute_from(src)),
+            false => Err(ValidityError::new()),
+        }
+    }
+
+    /// A value produced by a runtime-validated transmutation.
+    ///
+    /// `TryTransmuted<Dst>` is constructed with [TryTransmuted::try_new] whenever [try_transmute] may be used to convert `Src` to `Dst`; e.g.:
+    /// ```rust
+    /// let TryTransmuted(c) = TryTransmuted::<char>::try_new(0x1F980_u32)?;
+    /// let flag = TryTransmuted::<bool>::try_new(byte)?.into_inner();
+    /// ```
+    ///
+    /// As with [Transmuted][crate::transmute::Transmuted], an implementation of [TryFrom][core::convert::TryFrom]`<Src>` is impossible, as it would overlap with the standard library's blanket `impl<T, U: Into<T>> TryFrom<U> for T` at `Src = TryTransmuted<Dst>`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    #[repr(transparent)]
+    pub struct TryTransmuted<T>(pub T);
+
+    impl<T> TryTransmuted<T> {
+        /// Transmute `src` into a `T`, checking its validity at runtime. See [try_transmute].
+        #[inline]
+        pub fn try_new<Src>(src: Src) -> Result<Self, ValidityError>
+        where
+            T: TransmuteFrom<Src, NeglectValidity> + CheckBitValidity,
+            for<'a> &'a [u8]: CastFrom<&'a [Src]>,
+        {
+            try_transmute(src).map(TryTransmuted)
+        }
+
+        /// Unwraps the transmuted value.
+        #[inline(always)]
+        pub fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    /// A value whose bit validity has been checked at runtime.
+    ///
+    /// Values whose validity was checked once need not be re-validated on each access: `Validated<T>` dereferences to `T` at no cost. For instance, to validate a record of a memory-mapped file once, and then read its fields freely:
+    /// ```rust
+    /// let record: &Validated<Record> = Validated::from_bytes(&map[offset..])?;
+    /// match record.kind { ... }
+    /// ```
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Validated<T>(T);
+
+    impl<T> Validated<T> {
+        /// Transmute `src` into a `T`, checking its validity at runtime. See [try_transmute].
+        #[inline]
+        pub fn new<Src>(src: Src) -> Result<Self, ValidityError>
+        where
+            T: TransmuteFrom<Src, NeglectValidity> + CheckBitValidity,
+            for<'a> &'a [u8]: CastFrom<&'a [Src]>,
+        {
+            try_transmute(src).map(Validated)
+        }
+
+        /// View the leading bytes of `bytes` as a `T`, checking their alignment and validity at runtime.
+        ///
+        /// This produces an error if `bytes` is too short, misaligned, or not a bit-valid instance of `T`.
+        #[inline]
+        pub fn from_bytes<'a>(bytes: &'a [u8]) -> Result<&'a Self, ValidityError>
+        where
+            &'a [T]: CastFrom<&'a [u8], (NeglectAlignment, NeglectValidity)>,
+            T: CheckBitValidity,
+        {
+            let size = size_of::<T>();
+            if bytes.len() < size || !crate::mem::runtime::is_aligned_to::<T>(bytes.as_ptr()) {
+                return Err(ValidityError::new());
+            }
+            check_slice::<T>(&bytes[..size])?;
+            // Safe because we've dynamically verified the alignment and
+            // validity requirements, whose static checks we chose to neglect,
+            // and because `Validated<T>` is a transparent wrapper around `T`.
+            let vals: &[T] = unsafe { CastFrom::<_, (NeglectAlignment, NeglectValidity)>::unsafe_cast_from(bytes) };
+            Ok(unsafe { &*vals.as_ptr().cast::<Self>() })
+        }
+
+        /// Unwraps the validated value.
+        #[inline(always)]
+        pub fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    impl<T> core::ops::Deref for Validated<T> {
+        type Target = T;
+
+        #[inline(always)]
+        fn deref(&self) -> &T {
+            &self.0
+        }
+    }
+
+    /// Cross-check the [CheckBitValidity] implementation of a small type against an oracle, for every bit pattern.
+    ///
+    /// This enumerates all `2^(8 × size_of::<T>())` bit patterns of `T`, and produces the first for which [CheckBitValidity::is_bit_valid] disagrees with `oracle`. It catches bugs in validators of, e.g., enums with sparse discriminants:
+    /// ```rust
+    /// #[derive(PromiseTransmutableFrom)]
+    /// #[repr(u16)]
+    /// enum Port { Http = 80, Https = 443, Dns = 53 }
+    ///
+    /// #[test]
+    /// fn port_validator() {
+    ///     let oracle = |b: &[u8]| matches!(u16::from_ne_bytes([b[0], b[1]]), 80 | 443 | 53);
+    ///     assert_eq!(exhaustive_check::<Port>(2, oracle), Ok(()));
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `size_of::<T>()` exceeds `max_size`, to guard against accidentally enumerating the bit patterns of a large type. `max_size` may be at most `4`.
+    pub fn exhaustive_check<T, F>(max_size: usize, oracle: F) -> Result<(), Vec<u8>>
+    where
+        T: CheckBitValidity,
+        F: Fn(&[u8]) -> bool,
+    {
+        let size = size_of::<T>();
+        assert!(max_size <= 4, "exhaustive checks of types larger than 4 bytes are not supported");
+        assert!(size <= max_size, "type is too large to check exhaustively");
+        for pattern in 0..(1u64 << (8 * size)) {
+            let bytes = &pattern.to_le_bytes()[..size];
+            if T::is_bit_valid(bytes) != oracle(bytes) {
+                return Err(bytes.to_vec());
+            }
+        }
+        Ok(())
+    }
+
+    /// Produces `true` if no bit is set in `bytes` that is unset in `mask`.
+    #[inline]
+    pub fn is_within_mask(bytes: &[u8], mask: &[u8]) -> bool {
+        bytes.iter().zip(mask).all(|(&byte, &mask)| byte & !mask == 0)
+    }
+
+    /// Implement [CheckBitValidity] for an integer-backed flags type, which is valid exactly for the bit patterns within a mask.
+    ///
+    /// `$t` must have the layout of its backing integer `$bits`; compilation fails if their sizes differ. For instance, to accept `u32 -> Permissions` transmutes whenever no undefined bits are set:
+    /// ```rust
+    /// /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    /// #[repr(transparent)]
+    /// pub struct Permissions(u32);
+    ///
+    /// impl Permissions {
+    ///     pub const READ: u32 = 0b001;
+    ///     pub const WRITE: u32 = 0b010;
+    ///     pub const EXEC: u32 = 0b100;
+    /// }
+    ///
+    /// impl_bit_mask!(Permissions, u32, Permissions::READ | Permissions::WRITE | Permissions::EXEC);
+    ///
+    /// let perms: Permissions = try_transmute(mode)?;
+    /// ```
+    /// For types declared with the [`bitflags`](https://docs.rs/bitflags) crate, see [impl_flags][crate::interop::bitflags::impl_flags].
+    pub macro impl_bit_mask($t:ty, $bits:ty, $mask:expr) {
+        const _: [(); 0] = [(); core::mem::size_of::<$t>() - core::mem::size_of::<$bits>()];
+
+        unsafe impl $crate::validity::CheckBitValidity for $t {
+            fn is_bit_valid(bytes: &[u8]) -> bool {
+                $crate::validity::is_within_mask(bytes, &<$bits>::to_ne_bytes($mask))
+            }
+        }
+    }
+
+    /// Validated conversions into types with invalid bit patterns.
+    ///
+    /// These conversions package the pattern of the [NeglectValidity] documentation, so it need not be reimplemented; e.g.:
+    /// ```rust
+    /// use core::convert::transmute::validity::validate::TryTransmuteInto;
+    ///
+    /// let flag: bool = byte.try_into_bool()?;
+    /// let c: char = word.try_into_char()?;
+    /// let n: NonZeroU32 = word.try_into_nonzero()?;
+    /// let op: Opcode = byte.try_transmute_into()?;
+    /// ```
+    pub mod validate {
+        use super::*;
+
+        /// Validated conversions of `Self` into types with invalid bit patterns.
+        ///
+        /// This trait is implemented for all types; each of its methods is available whenever [try_transmute] may be used for the corresponding conversion.
+        pub trait TryTransmuteInto: Sized {
+            /// Convert `self` into a `bool`, if it is `0` or `1`.
+            #[inline]
+            fn try_into_bool(self) -> Result<bool, ValidityError>
+            where
+                bool: TransmuteFrom<Self, NeglectValidity>,
+                for<'a> &'a [u8]: CastFrom<&'a [Self]>,
+            {
+                try_transmute(self)
+            }
+
+            /// Convert `self` into a `char`, if it is a Unicode scalar value.
+            #[inline]
+            fn try_into_char(self) -> Result<char, ValidityError>
+            where
+                char: TransmuteFrom<Self, NeglectValidity>,
+                for<'a> &'a [u8]: CastFrom<&'a [Self]>,
+            {
+                try_transmute(self)
+            }
+
+            /// Convert `self` into a non-zero integer type `N` (e.g., [NonZeroU32]), if it is not zero.
+            #[inline]
+            fn try_into_nonzero<N>(self) -> Result<N, ValidityError>
+            where
+                N: NonZero + TransmuteFrom<Self, NeglectValidity>,
+                for<'a> &'a [u8]: CastFrom<&'a [Self]>,
+            {
+                try_transmute(self)
+            }
+
+            /// Convert `self` into any type implementing [CheckBitValidity]; e.g., an enum deriving [PromiseTransmutableFrom][crate::transmute::stability::PromiseTransmutableFrom].
+            #[inline]
+            fn try_transmute_into<Dst>(self) -> Result<Dst, ValidityError>
+            where
+                Dst: TransmuteFrom<Self, NeglectValidity> + CheckBitValidity,
+                for<'a> &'a [u8]: CastFrom<&'a [Self]>,
+            {
+                try_transmute(self)
+            }
+        }
+
+        impl<T> TryTransmuteInto for T {}
+
+        /// The non-zero integer types.
+        pub trait NonZero: CheckBitValidity + private::Sealed {}
+
+        mod private {
+            pub trait Sealed {}
+        }
+
+        macro_rules! nonzero {
+            ($($ty:ident)*) => {$(
+                impl private::Sealed for $ty {}
+                impl NonZero for $ty {}
+            )*}
+        }
+
+        nonzero! {
+            NonZeroI8 NonZeroI16 NonZeroI32 NonZeroI64 NonZeroI128 NonZeroIsize
+            NonZeroU8 NonZeroU16 NonZeroU32 NonZeroU64 NonZeroU128 NonZeroUsize
+        }
+    }
+
+    /// Check that `bytes` consists of bit-valid instances of `T`, ignoring any trailing bytes.
+    #[inline]
+    pub(crate) fn check_slice<T: CheckBitValidity>(bytes: &[u8]) -> Result<(), ValidityError> {
+        let size = size_of::<T>();
+        if T::is_slice_bit_valid(&bytes[..bytes.len() / size * size]) {
+            return Ok(());
+        }
+        // Only once the fast check has failed is the invalid element located.
+        match bytes.chunks_exact(size).position(|chunk| !T::is_bit_valid(chunk)) {
+            None => Ok(()),
+            Some(i) => {
+                let chunk = &bytes[i * size..][..size];
+                Err(T::locate_invalid(chunk).map_or(ValidityError::new(), |field| ValidityError::at(i * size, field)))
+            }
+        }
+    }
+
+    unsafe impl CheckBitValidity for     ! { fn is_bit_valid(_: &[u8]) -> bool { false } }
+    unsafe impl CheckBitValidity for    () { fn is_bit_valid(_: &[u8]) -> bool { true } }
+
+    unsafe impl CheckBitValidity for   f32 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for   f64 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+
+    unsafe impl CheckBitValidity for    i8 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for   i16 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for   i32 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for   i64 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for  i128 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for isize { fn is_bit_valid(_: &[u8]) -> bool { true } }
+
+    unsafe impl CheckBitValidity for    u8 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for   u16 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for   u32 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for   u64 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for  u128 { fn is_bit_valid(_: &[u8]) -> bool { true } }
+    unsafe impl CheckBitValidity for usize { fn is_bit_valid(_: &[u8]) -> bool { true } }
+
+    unsafe impl CheckBitValidity for bool {
+        fn is_bit_valid(bytes: &[u8]) -> bool { bytes[0] < 2 }
+        fn is_slice_bit_valid(bytes: &[u8]) -> bool { kernels::all_below(bytes, 2) }
+    }
+
+    /// Vectorized validation kernels, selected at runtime according to the features of the CPU.
+    mod kernels {
+        /// Produces `true` if every byte of `bytes` is less than `bound`.
+        #[inline]
+        pub fn all_below(bytes: &[u8], bound: u8) -> bool {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            {
+                if is_x86_feature_detected!("avx2") {
+                    // Safe because we've dynamically verified that the CPU supports AVX2.
+                    return unsafe { x86::all_below_avx2(bytes, bound) };
+                }
+                // SSE2 is part of the x86_64 baseline, so is only detected on x86.
+                if cfg!(target_arch = "x86_64") || is_x86_feature_detected!("sse2") {
+                    // Safe because the CPU supports SSE2.
+                    return unsafe { x86::all_below_sse2(bytes, bound) };
+                }
+            }
+            #[cfg(target_arch = "aarch64")]
+            {
+                if is_aarch64_feature_detected!("neon") {
+                    // Safe because we've dynamically verified that the CPU supports NEON.
+                    return unsafe { aarch64::all_below_neon(bytes, bound) };
+                }
+            }
+            all_below_scalar(bytes, bound)
+        }
+
+        /// Check that `bytes` is valid UTF-8, skipping the full decoder if it is entirely ASCII.
+        #[inline]
+        pub fn check_utf8(bytes: &[u8]) -> Result<(), core::str::Utf8Error> {
+            match all_below(bytes, 0x80) {
+                true => Ok(()),
+                false => core::str::from_utf8(bytes).map(drop),
+            }
+        }
+
+        #[inline]
+        fn all_below_scalar(bytes: &[u8], bound: u8) -> bool {
+            bytes.iter().all(|&b| b < bound)
+        }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        mod x86 {
+            #[cfg(target_arch = "x86")]
+            use core::arch::x86::*;
+            #[cfg(target_arch = "x86_64")]
+            use core::arch::x86_64::*;
+
+            // Each kernel compares the unsigned maximum of each byte and
+            // `bound - 1` against `bound - 1`, which are equal exactly when
+            // the byte is less than `bound`.
+
+            #[target_feature(enable = "sse2")]
+            pub(crate) unsafe fn all_below_sse2(bytes: &[u8], bound: u8) -> bool {
+                if bound == 0 { return bytes.is_empty(); }
+                let max = _mm_set1_epi8((bound - 1) as i8);
+                let mut chunks = bytes.chunks_exact(16);
+                for chunk in &mut chunks {
+                    let v = _mm_loadu_si128(chunk.as_ptr().cast::<__m128i>());
+                    if _mm_movemask_epi8(_mm_cmpeq_epi8(_mm_max_epu8(v, max), max)) != 0xFFFF {
+                        return false;
+                    }
+                }
+                super::all_below_scalar(chunks.remainder(), bound)
+            }
+
+            #[target_feature(enable = "avx2")]
+            pub(crate) unsafe fn all_below_avx2(bytes: &[u8], bound: u8) -> bool {
+                if bound == 0 { return bytes.is_empty(); }
+                let max = _mm256_set1_epi8((bound - 1) as i8);
+                let mut chunks = bytes.chunks_exact(32);
+                for chunk in &mut chunks {
+                    let v = _mm256_loadu_si256(chunk.as_ptr().cast::<__m256i>());
+                    if _mm256_movemask_epi8(_mm256_cmpeq_epi8(_mm256_max_epu8(v, max), max)) != -1 {
+                        return false;
+                    }
+                }
+                all_below_sse2(chunks.remainder(), bound)
+            }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        mod aarch64 {
+            use core::arch::aarch64::*;
+
+            // The kernel compares the horizontal maximum of each vector of
+            // bytes against `bound - 1`.
+
+            #[target_feature(enable = "neon")]
+            pub(crate) unsafe fn all_below_neon(bytes: &[u8], bound: u8) -> bool {
+                if bound == 0 { return bytes.is_empty(); }
+                let mut chunks = bytes.chunks_exact(16);
+                for chunk in &mut chunks {
+                    if vmaxvq_u8(vld1q_u8(chunk.as_ptr())) > bound - 1 {
+                        return false;
+                    }
+                }
+                super::all_below_scalar(chunks.remainder(), bound)
+            }
+        }
+    }
+
+    pub(crate) use kernels::check_utf8;
+
+    #[doc(hidden)]
+    pub use kernels::all_below as __all_below;
+
+    unsafe impl CheckBitValidity for char {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            let bits = u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            core::char::from_u32(bits).is_some()
+        }
+    }
+
+    use core::num::*;
+    unsafe impl CheckBitValidity for    NonZeroI8 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for   NonZeroI16 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for   NonZeroI32 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for   NonZeroI64 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for  NonZeroI128 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for NonZeroIsize { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+
+    unsafe impl CheckBitValidity for    NonZeroU8 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for   NonZeroU16 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for   NonZeroU32 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for   NonZeroU64 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for  NonZeroU128 { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+    unsafe impl CheckBitValidity for NonZeroUsize { fn is_bit_valid(bytes: &[u8]) -> bool { bytes.iter().any(|&b| b != 0) } }
+
+    use core::marker::PhantomData;
+    unsafe impl<T: ?Sized> CheckBitValidity for PhantomData<T> { fn is_bit_valid(_: &[u8]) -> bool { true } }
+
+    unsafe impl<T, const N: usize> CheckBitValidity for [T; N]
+    where
+        T: CheckBitValidity,
+    {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            match size_of::<T>() {
+                0 => N == 0 || T::is_bit_valid(bytes),
+                size => bytes.chunks_exact(size).all(T::is_bit_valid),
+            }
+        }
+    }
+}
+
+/// (Extension) A compatibility layer for the API of the [`safe-transmute`](https://docs.rs/safe-transmute) crate.
+///
+/// The functions and guards of this module mirror those of `safe-transmute`, but their soundness is guaranteed by the bounds of [TransmuteFrom] and [CastFrom][cast::CastFrom], rather than by the caller's implementations of `safe-transmute`'s unsafe `TriviallyTransmutable` trait. Migrating is usually a matter of replacing `safe_transmute::` with this module's path; e.g.:
+/// ```rust
+/// use core::convert::transmute::compat::{transmute_one, transmute_many_pedantic};
+///
+/// let header: Header = transmute_one(&packet[..16])?;
+/// let samples: &[u16] = transmute_many_pedantic(&packet[16..])?;
+/// ```
+#[unstable(feature = "safe_transmute_compat", issue = "none")]
+pub mod compat {
+    use crate::{
+        cast::CastFrom,
+        transmute::options::NeglectAlignment,
+    };
+    use core::{fmt, marker::PhantomData, mem::{align_of, size_of}, ptr};
+
+    /// Why a [Guard] rejected a byte slice.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ErrorReason {
+        /// Too few bytes to fill even one instance of the target type.
+        NotEnoughBytes,
+        /// More bytes than fill exactly one instance of the target type.
+        TooManyBytes,
+        /// The number of bytes is not a multiple of the size of the target type.
+        InexactByteCount,
+        /// The target type is zero-sized, so the number of its instances in the bytes is undefined.
+        ZeroSized,
+    }
+
+    /// The error produced when a [Guard] rejects a byte slice.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct GuardError {
+        /// The minimum number of bytes the guard required.
+        pub required: usize,
+        /// The number of bytes that were provided.
+        pub actual: usize,
+        /// Why the bytes were rejected.
+        pub reason: ErrorReason,
+    }
+
+    /// The error produced by this module's transmutation functions.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Error<'a, S, T> {
+        /// The byte slice was rejected by a [Guard].
+        Guard(GuardError),
+        /// The byte slice was not suitably aligned for the target type.
+        Unaligned(UnalignedError<'a, S, T>),
+    }
+
+    /// The error produced when a byte slice is not suitably aligned for the target type.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct UnalignedError<'a, S, T> {
+        /// The number of leading bytes to skip to reach an aligned address.
+        pub offset: usize,
+        /// The misaligned source.
+        pub source: &'a [S],
+        target: PhantomData<T>,
+    }
+
+    impl<S, T> fmt::Display for Error<'_, S, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Error::Guard(err) => write!(f, "{:?}: required {} bytes, got {}", err.reason, err.required, err.actual),
+                Error::Unaligned(err) => write!(f, "source is misaligned by {} bytes", err.offset),
+            }
+        }
+    }
+
+    /// A check on the length of a byte slice, prior to transmuting it to a slice of `T`.
+    pub trait Guard {
+        /// Check that `bytes` may be transmuted to a slice of `T`.
+        fn check<T>(bytes: &[u8]) -> Result<(), GuardError>;
+    }
+
+    /// Permits exactly one instance of the target type.
+    pub struct SingleValueGuard;
+
+    /// Permits one or more instances of the target type, with no trailing bytes.
+    pub struct PedanticGuard;
+
+    /// Permits any number of instances of the target type, with no trailing bytes.
+    pub struct AllOrNothingGuard;
+
+    /// Permits one or more instances of the target type; trailing bytes are ignored.
+    pub struct SingleManyGuard;
+
+    /// Permits any number of instances of the target type; trailing bytes are ignored.
+    pub struct PermissiveGuard;
+
+    fn guard_error<T>(bytes: &[u8], reason: ErrorReason) -> GuardError {
+        GuardError { required: size_of::<T>(), actual: bytes.len(), reason }
+    }
+
+    impl Guard for SingleValueGuard {
+        fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+            match bytes.len() {
+                n if n < size_of::<T>() => Err(guard_error::<T>(bytes, ErrorReason::NotEnoughBytes)),
+                n if n > size_of::<T>() => Err(guard_error::<T>(bytes, ErrorReason::TooManyBytes)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    impl Guard for PedanticGuard {
+        fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+            match bytes.len() {
+                _ if size_of::<T>() == 0 => Err(guard_error::<T>(bytes, ErrorReason::ZeroSized)),
+                n if n < size_of::<T>() => Err(guard_error::<T>(bytes, ErrorReason::NotEnoughBytes)),
+                n if n % size_of::<T>() != 0 => Err(guard_error::<T>(bytes, ErrorReason::InexactByteCount)),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    impl Guard for AllOrNothingGuard {
+        fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+            match bytes.len().checked_rem(size_of::<T>()) {
+                None => Err(guard_error::<T>(bytes, ErrorReason::ZeroSized)),
+                Some(0) => Ok(()),
+                Some(_) => Err(guard_error::<T>(bytes, ErrorReason::InexactByteCount)),
+            }
+        }
+    }
+
+    impl Guard for SingleManyGuard {
+        fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+            match bytes.len() < size_of::<T>() {
+                true => Err(guard_error::<T>(bytes, ErrorReason::NotEnoughBytes)),
+                false => Ok(()),
+            }
+        }
+    }
+
+    impl Guard for PermissiveGuard {
+        fn check<T>(_: &[u8]) -> Result<(), GuardError> {
+            Ok(())
+        }
+    }
+
+    /// Transmute a byte slice into a single instance of `T`, which need not be aligned.
+    ///
+    /// The slice must contain exactly `size_of::<T>()` bytes.
+    #[inline]
+    pub fn transmute_one<'a, T>(bytes: &'a [u8]) -> Result<T, Error<'a, u8, T>>
+    where
+        for<'i, 'o> &'o [T]: CastFrom<&'i [u8], NeglectAlignment>,
+    {
+        SingleValueGuard::check::<T>(bytes).map_err(Error::Guard)?;
+        // Safe because the bound above guarantees that every initialized
+        // byte sequence is a valid `T`, and the guard ensures that there are
+        // enough bytes; the read does not require alignment.
+        Ok(unsafe { ptr::read_unaligned(bytes.as_ptr().cast::<T>()) })
+    }
+
+    /// Transmute a byte slice into a slice of `T`, checking its length with the guard `G`.
+    ///
+    /// This produces [Error::Unaligned] if `bytes` is not suitably aligned for `T`.
+    #[inline]
+    pub fn transmute_many<'a, T, G>(bytes: &'a [u8]) -> Result<&'a [T], Error<'a, u8, T>>
+    where
+        G: Guard,
+        for<'i, 'o> &'o [T]: CastFrom<&'i [u8], NeglectAlignment>,
+    {
+        G::check::<T>(bytes).map_err(Error::Guard)?;
+        match bytes.as_ptr().align_offset(align_of::<T>()) {
+            // Safe because we've dynamically verified the alignment
+            // requirement, whose static check we chose to neglect.
+            0 => Ok(unsafe { CastFrom::<_, NeglectAlignment>::unsafe_cast_from(bytes) }),
+            offset => Err(Error::Unaligned(UnalignedError { offset, source: bytes, target: PhantomData })),
+        }
+    }
+
+    /// Transmute a byte slice into a non-empty slice of `T`, with no trailing bytes.
+    #[inline]
+    pub fn transmute_many_pedantic<'a, T>(bytes: &'a [u8]) -> Result<&'a [T], Error<'a, u8, T>>
+    where
+        for<'i, 'o> &'o [T]: CastFrom<&'i [u8], NeglectAlignment>,
+    {
+        transmute_many::<T, PedanticGuard>(bytes)
+    }
+
+    /// Transmute a byte slice into a slice of `T`, ignoring any trailing bytes.
+    #[inline]
+    pub fn transmute_many_permissive<'a, T>(bytes: &'a [u8]) -> Result<&'a [T], Error<'a, u8, T>>
+    where
+        for<'i, 'o> &'o [T]: CastFrom<&'i [u8], NeglectAlignment>,
+    {
+        transmute_many::<T, PermissiveGuard>(bytes)
+    }
+
+    /// Transmute a slice of `T` into its bytes.
+    #[inline]
+    pub fn transmute_to_bytes<'a, T>(from: &'a [T]) -> &'a [u8]
+    where
+        &'a [u8]: CastFrom<&'a [T]>,
+    {
+        CastFrom::cast_from(from)
+    }
+}
+
+/// (Extension) Bridges to the traits of other transmutation crates.
+///
+/// Crates midway through migrating to [TransmuteFrom] may use these bridges to use both ecosystems against the same types, without duplicate derives. Blanket implementations in either direction are forbidden by coherence, so each bridge consists of a wrapper type (for using foreign-trait types with this crate) and a macro (for implementing foreign traits on types already transmutable here).
+#[unstable(feature = "transmute_interop", issue = "none")]
+pub mod interop {
+    /// Bridges to [`bytemuck`](https://docs.rs/bytemuck)'s `Pod` and `Zeroable` traits.
+    ///
+    /// ***This module requires the `bytemuck` feature.***
+    #[cfg(feature = "bytemuck")]
+    pub mod bytemuck {
+        use crate::{
+            transmute::stability::{PromiseTransmutableFrom, PromiseTransmutableInto},
+            validity::CheckBitValidity,
+        };
+
+        /// A [`bytemuck::Pod`](::bytemuck::Pod) type, usable with [TransmuteFrom][crate::transmute::TransmuteFrom] and [CastFrom][crate::cast::CastFrom].
+        ///
+        /// `Pod` types are, by definition, transmutable to and from any byte array of the same size. Wrapping such a type in `Pod` promises that layout is stable:
+        /// ```rust
+        /// let header: Pod<Header> = bytes.transmute_into();
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #[repr(transparent)]
+        pub struct Pod<T>(pub T);
+
+        impl<T: ::bytemuck::Pod> PromiseTransmutableInto for Pod<T> { type Archetype = Self; }
+        impl<T: ::bytemuck::Pod> PromiseTransmutableFrom for Pod<T> { type Archetype = Self; }
+
+        unsafe impl<T: ::bytemuck::Pod> CheckBitValidity for Pod<T> { fn is_bit_valid(_: &[u8]) -> bool { true } }
+
+        /// Implement [`bytemuck::Zeroable`](::bytemuck::Zeroable) and [`bytemuck::Pod`](::bytemuck::Pod) for a type that is stably transmutable to and from its bytes.
+        ///
+        /// This emits a compile error unless `$t` is [`Copy`], `'static`, and both transmutable from and into `[u8; size_of::<$t>()]`:
+        /// ```rust
+        /// #[derive(Clone, Copy, PromiseTransmutableFrom, PromiseTransmutableInto)]
+        /// #[repr(C)]
+        /// pub struct Header { pub kind: u32, pub len: u32 }
+        ///
+        /// impl_pod!(Header);
+        ///
+        /// let headers: &[Header] = bytemuck::cast_slice(bytes);
+        /// ```
+        pub macro impl_pod($t:ty) {
+            const _: () = {
+                use $crate::{mem::Bytes, transmute::TransmuteFrom};
+
+                fn assert_pod()
+                where
+                    $t: TransmuteFrom<Bytes<$t>>,
+                    Bytes<$t>: TransmuteFrom<$t>,
+                {}
+            };
+
+            unsafe impl ::bytemuck::Zeroable for $t {}
+            unsafe impl ::bytemuck::Pod for $t {}
+        }
+    }
+
+    /// Bridges to [`zerocopy`](https://docs.rs/zerocopy)'s `AsBytes`, `FromZeroes`, `FromBytes` and `Unaligned` traits.
+    ///
+    /// ***This module requires the `zerocopy` feature.***
+    #[cfg(feature = "zerocopy")]
+    pub mod zerocopy {
+        use crate::{
+            transmute::stability::{PromiseTransmutableFrom, PromiseTransmutableInto},
+            validity::CheckBitValidity,
+        };
+
+        /// A type implementing `zerocopy`'s traits, usable with [TransmuteFrom][crate::transmute::TransmuteFrom] and [CastFrom][crate::cast::CastFrom].
+        ///
+        /// `Zerocopy<T>` is transmutable into other types if `T` implements [`AsBytes`](::zerocopy::AsBytes), and from other types if `T` implements [`FromBytes`](::zerocopy::FromBytes):
+        /// ```rust
+        /// let header: Zerocopy<Header> = bytes.transmute_into();
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        #[repr(transparent)]
+        pub struct Zerocopy<T>(pub T);
+
+        impl<T: ::zerocopy::AsBytes> PromiseTransmutableInto for Zerocopy<T> { type Archetype = Self; }
+        impl<T: ::zerocopy::FromBytes> PromiseTransmutableFrom for Zerocopy<T> { type Archetype = Self; }
+
+        unsafe impl<T: ::zerocopy::FromBytes> CheckBitValidity for Zerocopy<T> { fn is_bit_valid(_: &[u8]) -> bool { true } }
+
+        /// Implement [`zerocopy::AsBytes`](::zerocopy::AsBytes) for a type that is stably transmutable into its bytes.
+        ///
+        /// This emits a compile error unless `$t` is transmutable into `[u8; size_of::<$t>()]`; i.e., unless it has no padding.
+        pub macro impl_as_bytes($t:ty) {
+            const _: () = {
+                use $crate::{mem::Bytes, transmute::TransmuteFrom};
+
+                fn assert_as_bytes()
+                where
+                    Bytes<$t>: TransmuteFrom<$t>,
+                {}
+            };
+
+            unsafe impl ::zerocopy::AsBytes for $t {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+        }
+
+        /// Implement [`zerocopy::FromZeroes`](::zerocopy::FromZeroes) and [`zerocopy::FromBytes`](::zerocopy::FromBytes) for a type that is stably transmutable from any bytes.
+        ///
+        /// This emits a compile error unless `$t` is transmutable from `[u8; size_of::<$t>()]`.
+        pub macro impl_from_bytes($t:ty) {
+            const _: () = {
+                use $crate::{mem::Bytes, transmute::TransmuteFrom};
+
+                fn assert_from_bytes()
+                where
+                    $t: TransmuteFrom<Bytes<$t>>,
+                {}
+            };
+
+            unsafe impl ::zerocopy::FromZeroes for $t {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+
+            unsafe impl ::zerocopy::FromBytes for $t {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+        }
+
+        /// Implement [`zerocopy::Unaligned`](::zerocopy::Unaligned) for a type with an alignment of `1`.
+        ///
+        /// This emits a compile error unless `$t` is no more aligned than `u8`.
+        pub macro impl_unaligned($t:ty) {
+            const _: () = {
+                use $crate::mem::AlignLtEq;
+
+                fn assert_unaligned()
+                where
+                    $t: AlignLtEq<u8>,
+                {}
+            };
+
+            unsafe impl ::zerocopy::Unaligned for $t {
+                fn only_derive_is_allowed_to_implement_this_trait() {}
+            }
+        }
+    }
+
+    /// Generation of arbitrary values with [`arbitrary`](https://docs.rs/arbitrary), for fuzzing.
+    ///
+    /// ***This module requires the `arbitrary` feature.***
+    #[cfg(feature = "arbitrary")]
+    pub mod arbitrary {
+        use crate::{
+            mem::FromAnyBytes,
+            transmute::options::NeglectValidity,
+            validity::CheckBitValidity,
+        };
+        use ::arbitrary::{Arbitrary, Error, Result, Unstructured};
+        use core::{mem::size_of, ptr};
+
+        /// The number of times [Valid] draws bytes before giving up on producing a bit-valid value.
+        const ATTEMPTS: usize = 16;
+
+        /// An arbitrary value of a type for which every bit pattern is valid, drawn from the fuzzer's bytes.
+        ///
+        /// ```rust
+        /// fuzz_target!(|header: AnyBytes<Header>| { parse(header.0) });
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(transparent)]
+        pub struct AnyBytes<T>(pub T);
+
+        impl<'a, T> Arbitrary<'a> for AnyBytes<T>
+        where
+            T: FromAnyBytes,
+        {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let bytes = u.bytes(size_of::<T>())?;
+                // Safe because `T: FromAnyBytes`, and the read need not be aligned.
+                Ok(AnyBytes(unsafe { ptr::read_unaligned(bytes.as_ptr().cast::<T>()) }))
+            }
+
+            fn size_hint(_: usize) -> (usize, Option<usize>) {
+                (size_of::<T>(), Some(size_of::<T>()))
+            }
+        }
+
+        /// An arbitrary bit-valid value of a type with invalid bit patterns (e.g., an enum), drawn from the fuzzer's bytes.
+        ///
+        /// Bytes are drawn until they are a valid instance of `T`, per its [CheckBitValidity] implementation.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[repr(transparent)]
+        pub struct Valid<T>(pub T);
+
+        impl<'a, T> Arbitrary<'a> for Valid<T>
+        where
+            T: FromAnyBytes<NeglectValidity> + CheckBitValidity,
+        {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                for _ in 0..ATTEMPTS {
+                    let bytes = u.bytes(size_of::<T>())?;
+                    if T::is_bit_valid(bytes) {
+                        // Safe because we've dynamically verified that `bytes`
+                        // is a bit-valid `T`, and the read need not be aligned.
+                        return Ok(Valid(unsafe { ptr::read_unaligned(bytes.as_ptr().cast::<T>()) }));
+                    }
+                }
+                Err(Error::IncorrectFormat)
+            }
+
+            fn size_hint(_: usize) -> (usize, Option<usize>) {
+                (size_of::<T>(), Some(size_of::<T>() * ATTEMPTS))
+            }
+        }
+    }
+
+    /// Typed access to the linear memory of a WebAssembly guest.
+    ///
+    /// Host runtimes expose a guest's linear memory as a byte slice, and guest pointers as `u32` offsets into it. These functions read and write plain-old-data types at such offsets, with bounds and alignment checked at runtime; e.g.:
+    /// ```rust
+    /// let request: Request = wasm::read(memory.data(&store), request_ptr)?;
+    /// wasm::write(memory.data_mut(&mut store), response_ptr, &response)?;
+    /// ```
+    ///
+    /// WebAssembly linear memory is little-endian, so the layouts of types exchanged with guests match only on little-endian hosts; accordingly, this module is only available on such hosts.
+    ///
+    /// ***This module requires the `wasm` feature.***
+    #[cfg(all(feature = "wasm", target_endian = "little"))]
+    pub mod wasm {
+        use crate::mem::{FromAnyBytes, NoUninit};
+        use core::{fmt, mem::size_of, ptr};
+
+        /// The error produced when accessing linear memory fails.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum MemoryError {
+            /// The value does not lie entirely within linear memory.
+            OutOfBounds,
+            /// The value is not suitably aligned for a reference to it.
+            Misaligned,
+        }
+
+        impl fmt::Display for MemoryError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    MemoryError::OutOfBounds => f.write_str("access out of bounds of linear memory"),
+                    MemoryError::Misaligned => f.write_str("misaligned access of linear memory"),
+                }
+            }
+        }
+
+        impl std::error::Error for MemoryError {}
+
+        /// The bytes of `memory` occupied by a `T` at the guest pointer `offset`.
+        fn range<T>(memory: &[u8], offset: u32) -> Result<core::ops::Range<usize>, MemoryError> {
+            let start = offset as usize;
+            let end = start.checked_add(size_of::<T>()).ok_or(MemoryError::OutOfBounds)?;
+            match end <= memory.len() {
+                true => Ok(start..end),
+                false => Err(MemoryError::OutOfBounds),
+            }
+        }
+
+        /// Read a copy of the `T` at the guest pointer `offset`, which need not be aligned.
+        pub fn read<T: FromAnyBytes>(memory: &[u8], offset: u32) -> Result<T, MemoryError> {
+            let bytes = &memory[range::<T>(memory, offset)?];
+            // Safe because any bytes are a valid `T`, and the read need not be aligned.
+            Ok(unsafe { ptr::read_unaligned(bytes.as_ptr().cast::<T>()) })
+        }
+
+        /// Write `val` to the guest pointer `offset`, which need not be aligned.
+        pub fn write<T: NoUninit>(memory: &mut [u8], offset: u32, val: &T) -> Result<(), MemoryError> {
+            let range = range::<T>(memory, offset)?;
+            let bytes = &mut memory[range];
+            // Safe because `T` has no uninitialized bytes, so linear memory
+            // remains initialized, and the write need not be aligned.
+            Ok(unsafe { ptr::copy_nonoverlapping((val as *const T).cast::<u8>(), bytes.as_mut_ptr(), size_of::<T>()) })
+        }
+
+        /// A reference to the `T` at the guest pointer `offset`, which must be aligned.
+        pub fn view<T: FromAnyBytes>(memory: &[u8], offset: u32) -> Result<&T, MemoryError> {
+            let bytes = &memory[range::<T>(memory, offset)?];
+            if !crate::mem::runtime::is_aligned_to::<T>(bytes.as_ptr()) {
+                return Err(MemoryError::Misaligned);
+            }
+            // Safe because any bytes are a valid `T`, and we've dynamically
+            // verified that the reference is aligned.
+            Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+        }
+    }
+
+    /// Strategies for generating values with [`proptest`](https://docs.rs/proptest).
+    ///
+    /// ***This module requires the `proptest` feature.***
+    #[cfg(feature = "proptest")]
+    pub mod proptest {
+        use crate::{
+            mem::FromAnyBytes,
+            transmute::options::NeglectValidity,
+            validity::CheckBitValidity,
+        };
+        use ::proptest::{collection::vec, prelude::*};
+        use core::{fmt::Debug, mem::size_of, ptr};
+
+        /// A strategy generating values of a type for which every bit pattern is valid, from random bytes; e.g.:
+        /// ```rust
+        /// proptest! {
+        ///     #[test]
+        ///     fn roundtrip(header in any_bytes::<Header>()) { ... }
+        /// }
+        /// ```
+        pub fn any_bytes<T>() -> impl Strategy<Value = T>
+        where
+            T: FromAnyBytes + Debug,
+        {
+            vec(any::<u8>(), size_of::<T>())
+                // Safe because `T: FromAnyBytes`, and the read need not be aligned.
+                .prop_map(|bytes| unsafe { ptr::read_unaligned(bytes.as_ptr().cast::<T>()) })
+        }
+
+        /// A strategy generating bit-valid values of a type with invalid bit patterns (e.g., an enum), from random bytes.
+        ///
+        /// Random bytes which are not a valid instance of `T`, per its [CheckBitValidity] implementation, are rejected.
+        pub fn valid<T>() -> impl Strategy<Value = T>
+        where
+            T: FromAnyBytes<NeglectValidity> + CheckBitValidity + Debug,
+        {
+            vec(any::<u8>(), size_of::<T>()).prop_filter_map("not a bit-valid instance", |bytes| {
+                match T::is_bit_valid(&bytes) {
+                    // Safe because we've dynamically verified that `bytes`
+                    // is a bit-valid `T`, and the read need not be aligned.
+                    true => Some(unsafe { ptr::read_unaligned(bytes.as_ptr().cast::<T>()) }),
+                    false => None,
+                }
+            })
+        }
+    }
+
+    /// Bridges to [`bitflags`](https://docs.rs/bitflags)'s `Flags` trait.
+    ///
+    /// ***This module requires the `bitflags` feature.***
+    #[cfg(feature = "bitflags")]
+    pub mod bitflags {
+        /// Promise the layout of a type declared with [`bitflags!`](::bitflags::bitflags), and implement [CheckBitValidity][crate::validity::CheckBitValidity] for it.
+        ///
+        /// The type is valid exactly for the bit patterns accepted by [`Flags::from_bits`](::bitflags::Flags::from_bits); i.e., those that set no undefined flags:
+        /// ```rust
+        /// bitflags! {
+        ///     pub struct Permissions: u32 {
+        ///         const READ = 0b001;
+        ///         const WRITE = 0b010;
+        ///         const EXEC = 0b100;
+        ///     }
+        /// }
+        ///
+        /// impl_flags!(Permissions);
+        ///
+        /// let perms: Permissions = try_transmute(mode)?;
+        /// let perms: &[Permissions] = try_cast_slice(modes)?;
+        /// ```
+        /// Types declared with `bitflags!` are transparent wrappers around their bits; compilation fails if the sizes of `$t` and its bits differ.
+        pub macro impl_flags($t:ty) {
+            impl $crate::transmute::stability::PromiseTransmutableInto for $t { type Archetype = Self; }
+            impl $crate::transmute::stability::PromiseTransmutableFrom for $t { type Archetype = Self; }
+
+            const _: [(); 0] = [(); core::mem::size_of::<$t>() - core::mem::size_of::<<$t as ::bitflags::Flags>::Bits>()];
+
+            unsafe impl $crate::validity::CheckBitValidity for $t {
+                fn is_bit_valid(bytes: &[u8]) -> bool {
+                    match $crate::bytes::read_unaligned_from::<<$t as ::bitflags::Flags>::Bits>(bytes) {
+                        Ok(bits) => <$t as ::bitflags::Flags>::from_bits(bits).is_some(),
+                        Err(_) => false,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// (Extension) Adapters over the byte representations of values.
+///
+/// Each adapter is implemented for types that contain no uninitialized bytes (i.e., [NoUninit][mem::NoUninit] types), whose bytes may be viewed without unsafe code.
+#[unstable(feature = "transmute_bytes", issue = "none")]
+pub mod bytes {
+    use crate::{
+        mem::{Bytes, FromAnyBytes, NoUninit, SizeEq},
+        transmute::{TransmuteFrom, safe_transmute, transmute_mut, transmute_ref},
+    };
+    use core::{fmt, mem::size_of, slice};
+
+    /// View the bytes of a value with no uninitialized bytes.
+    #[inline(always)]
+    fn bytes_of<T: NoUninit>(val: &T) -> &[u8] {
+        // Safe because `T: NoUninit`, so every byte of `val` is initialized.
+        unsafe { slice::from_raw_parts((val as *const T).cast::<u8>(), size_of::<T>()) }
+    }
+
+    /// Views of a value as a fixed-size byte array.
+    ///
+    /// Unlike byte *slices*, byte arrays carry their length in their type, as required by, e.g., block cipher APIs:
+    /// ```rust
+    /// let block: &[u8; 16] = nonce.as_byte_array();
+    /// cipher.encrypt_block(block.into());
+    /// ```
+    ///
+    /// This trait is implemented for all types; each of its methods is available whenever the corresponding transmutation is safe.
+    pub trait ByteArrayOf: Sized {
+        /// View `self` as a reference to a byte array.
+        #[inline(always)]
+        fn as_byte_array(&self) -> &Bytes<Self>
+        where
+            for<'a> &'a Bytes<Self>: TransmuteFrom<&'a Self>,
+        {
+            transmute_ref(self)
+        }
+
+        /// View `self` as a mutable reference to a byte array.
+        #[inline(always)]
+        fn as_byte_array_mut(&mut self) -> &mut Bytes<Self>
+        where
+            for<'a> &'a mut Bytes<Self>: TransmuteFrom<&'a mut Self>,
+        {
+            transmute_mut(self)
+        }
+
+        /// Convert `self` into a byte array.
+        #[inline(always)]
+        fn into_byte_array(self) -> Bytes<Self>
+        where
+            Bytes<Self>: TransmuteFrom<Self>,
+        {
+            safe_transmute(self)
+        }
+    }
+
+    impl<T> ByteArrayOf for T {}
+
+    /// Convert a value into its bit-identical integer representation.
+    ///
+    /// This generalizes [f32::to_bits] and [f64::to_bits] to every type with an integer-like representation of the same size, including user-defined fixed-point newtypes, so they share one API for, e.g., total-order sorting and serialization:
+    /// ```rust
+    /// let bits: u32 = 1.5f32.into_bits();
+    /// let bits: u16 = Fixed8x8::from_num(1.5).into_bits();
+    /// ```
+    pub trait IntoBits<Bits>: Sized {
+        /// Convert `self` into its bit-identical representation as `Bits`.
+        fn into_bits(self) -> Bits;
+    }
+
+    /// The representation `Bits` must have the same size as `Self`, and have no invalid or uninitialized bit patterns.
+    impl<T, Bits> IntoBits<Bits> for T
+    where
+        Bits: TransmuteFrom<T> + FromAnyBytes + NoUninit + SizeEq<T>,
+    {
+        #[inline(always)]
+        fn into_bits(self) -> Bits {
+            safe_transmute(self)
+        }
+    }
+
+    /// Convert a bit-identical integer representation into a value.
+    ///
+    /// This generalizes [f32::from_bits] and [f64::from_bits]; e.g.:
+    /// ```rust
+    /// let val: Fixed8x8 = FromBits::from_bits(0x0180u16);
+    /// ```
+    pub trait FromBits<Bits>: Sized {
+        /// Convert `bits` into its bit-identical value of `Self`.
+        fn from_bits(bits: Bits) -> Self;
+    }
+
+    /// Every `Bits` must be a valid `Self`.
+    impl<T, Bits> FromBits<Bits> for T
+    where
+        T: TransmuteFrom<Bits> + SizeEq<Bits>,
+        Bits: FromAnyBytes + NoUninit,
+    {
+        #[inline(always)]
+        fn from_bits(bits: Bits) -> Self {
+            safe_transmute(bits)
+        }
+    }
+
+    /// Renders the bytes of a value as a hex dump, with columns for the offset, hex, and ASCII representations of each row of 16 bytes.
+    ///
+    /// Binary-protocol developers may thereby log the raw layouts of their types; e.g.:
+    /// ```rust
+    /// log::trace!("sending:\n{}", HexDump(&header));
+    /// // 00000000  45 00 00 54 a6 f2 40 00  40 01 91 3c c0 a8 01 02  |E..T..@.@..<....|
+    /// // 00000010  c0 a8 01 01                                       |....|
+    /// ```
+    pub struct HexDump<'a, T>(pub &'a T);
+
+    impl<T: NoUninit> fmt::Display for HexDump<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for (row, chunk) in bytes_of(self.0).chunks(16).enumerate() {
+                if row > 0 {
+                    f.write_str("\n")?;
+                }
+                write!(f, "{:08x} ", row * 16)?;
+                for col in 0..16 {
+                    if col % 8 == 0 {
+                        f.write_str(" ")?;
+                    }
+                    match chunk.get(col) {
+                        Some(byte) => write!(f, "{:02x} ", byte)?,
+                        None => f.write_str("   ")?,
+                    }
+                }
+                f.write_str(" |")?;
+                for &byte in chunk {
+                    let c = if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' };
+                    write!(f, "{}", c)?;
+                }
+                f.write_str("|")?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: NoUninit> fmt::Debug for HexDump<'_, T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Display::fmt(self, f)
+        }
+    }
+
+    /// Compares values by their bytes (i.e., with `memcmp`), rather than by their [PartialEq] implementations.
+    ///
+    /// Two values are equal if their bytes are equal. For floating-point fields, this differs from [PartialEq]: `-0.0` and `0.0` are unequal, and `NaN` is equal to itself.
+    #[derive(Debug, Clone, Copy, Default)]
+    #[repr(transparent)]
+    pub struct ByteEq<T>(pub T);
+
+    impl<T: NoUninit> PartialEq for ByteEq<T> {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            bytes_of(&self.0) == bytes_of(&other.0)
+        }
+    }
+
+    impl<T: NoUninit> Eq for ByteEq<T> {}
+
+    /// Orders values by their bytes (i.e., with `memcmp`), rather than by their [Ord] implementations, so sort-heavy workloads over plain-old-data take the fast path; e.g.:
+    /// ```rust
+    /// let mut keys: Vec<ByteOrd<Key>> = ...;
+    /// keys.sort_unstable();
+    /// ```
+    ///
+    /// Bytes are compared lexicographically, in memory order. On little-endian targets, this does *not* agree with the numeric order of multi-byte integers.
+    #[derive(Debug, Clone, Copy, Default)]
+    #[repr(transparent)]
+    pub struct ByteOrd<T>(pub T);
+
+    impl<T: NoUninit> PartialEq for ByteOrd<T> {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            bytes_of(&self.0) == bytes_of(&other.0)
+        }
+    }
+
+    impl<T: NoUninit> Eq for ByteOrd<T> {}
+
+    impl<T: NoUninit> PartialOrd for ByteOrd<T> {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<T: NoUninit> Ord for ByteOrd<T> {
+        #[inline]
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            bytes_of(&self.0).cmp(bytes_of(&other.0))
+        }
+    }
+
+    /// Hashes and compares values by their bytes, rather than by their [Hash] and [PartialEq] implementations, so plain-old-data may key hash maps; e.g.:
+    /// ```rust
+    /// let mut seen: HashSet<ByteHash<Header>> = HashSet::new();
+    /// seen.insert(ByteHash(header));
+    /// ```
+    #[derive(Debug, Clone, Copy, Default)]
+    #[repr(transparent)]
+    pub struct ByteHash<T>(pub T);
+
+    impl<T: NoUninit> PartialEq for ByteHash<T> {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            bytes_of(&self.0) == bytes_of(&other.0)
+        }
+    }
+
+    impl<T: NoUninit> Eq for ByteHash<T> {}
+
+    impl<T: NoUninit> core::hash::Hash for ByteHash<T> {
+        #[inline]
+        fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+            state.write(bytes_of(&self.0))
+        }
+    }
+
+    /// The error produced when a byte slice is too short to read a value from.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct OutOfBoundsError {
+        /// The index one past the last byte of the value.
+        pub end: usize,
+        /// The length of the byte slice.
+        pub len: usize,
+    }
+
+    impl fmt::Display for OutOfBoundsError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "read of bytes up to {} is out of bounds of a slice of length {}", self.end, self.len)
+        }
+    }
+
+    impl std::error::Error for OutOfBoundsError {}
+
+    /// Read a copy of the `T` at the start of `bytes`, which need not be aligned.
+    ///
+    /// This wraps the usual [`ptr::read_unaligned`][core::ptr::read_unaligned] dance with a bounds check; e.g.:
+    /// ```rust
+    /// let header: Header = read_unaligned_from(packet)?;
+    /// ```
+    #[inline]
+    pub fn read_unaligned_from<T: FromAnyBytes>(bytes: &[u8]) -> Result<T, OutOfBoundsError> {
+        read_unaligned_at(bytes, 0)
+    }
+
+    /// Read a copy of the `T` at `offset` within `bytes`, which need not be aligned.
+    #[inline]
+    pub fn read_unaligned_at<T: FromAnyBytes>(bytes: &[u8], offset: usize) -> Result<T, OutOfBoundsError> {
+        let len = bytes.len();
+        match offset.checked_add(size_of::<T>()) {
+            // Safe because `T: FromAnyBytes`, so any initialized bytes are a
+            // valid `T`, we've verified that they are in bounds, and the read
+            // does not require alignment.
+            Some(end) if end <= len => Ok(unsafe { core::ptr::read_unaligned(bytes[offset..].as_ptr().cast::<T>()) }),
+            Some(end) => Err(OutOfBoundsError { end, len }),
+            None => Err(OutOfBoundsError { end: usize::MAX, len }),
+        }
+    }
+}
+
+/// (Extension) Demonstration: zero-copy parsing of UDP-over-IPv4-over-Ethernet frames.
+///
+/// This module exercises stability declarations, slice casts, and runtime validity checks together, as promised by the RFC's [fast parsing case study](https://github.com/jswrenn/project-safe-transmute/blob/rfc/rfcs/0000-safe-transmute.md#case-studies). Every header type has an alignment of `1`, so headers may be cast from any position within a frame; e.g.:
+/// ```rust
+/// let packet = parse_udp(&frame)?;
+/// println!("{}:{} says {:?}", Ipv4Addr::from(packet.ip.src), packet.udp.src_port.get(), packet.payload);
+/// ```
+#[unstable(feature = "transmute_demo", issue = "none")]
+pub mod net {
+    use crate::{
+        bytes::ByteArrayOf,
+        cast::CastFrom,
+        mem::Bytes,
+        transmute::{TransmuteFrom, options::{NeglectAlignment, NeglectValidity}, stability::{PromiseTransmutableFrom, PromiseTransmutableInto}},
+        validity::{CheckBitValidity, Validated, ValidityError, try_transmute},
+    };
+    use core::mem::size_of;
+
+    macro_rules! endian_ints {
+        ($($(#[$attr:meta])* $name:ident($int:ident, $n:literal, $to:ident, $from:ident);)*) => {$(
+            $(#[$attr])*
+            /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+            #[repr(transparent)]
+            pub struct $name(pub [u8; $n]);
+
+            impl $name {
+                /// Encode `val` in this type's byte order.
+                pub const fn new(val: $int) -> Self {
+                    $name(val.$to())
+                }
+
+                /// Decode the value in native byte order.
+                pub const fn get(self) -> $int {
+                    $int::$from(self.0)
+                }
+            }
+
+            impl PromiseTransmutableInto for $name {type Archetype = Self;}
+            impl PromiseTransmutableFrom for $name {type Archetype = Self;}
+
+            unsafe impl CheckBitValidity for $name { fn is_bit_valid(_: &[u8]) -> bool { true } }
+        )*}
+    }
+
+    endian_ints! {
+        /// A big-endian `u16`, with an alignment of `1`.
+        U16Be(u16, 2, to_be_bytes, from_be_bytes);
+        /// A big-endian `u32`, with an alignment of `1`.
+        U32Be(u32, 4, to_be_bytes, from_be_bytes);
+        /// A big-endian `u64`, with an alignment of `1`.
+        U64Be(u64, 8, to_be_bytes, from_be_bytes);
+        /// A little-endian `u16`, with an alignment of `1`.
+        U16Le(u16, 2, to_le_bytes, from_le_bytes);
+        /// A little-endian `u32`, with an alignment of `1`.
+        U32Le(u32, 4, to_le_bytes, from_le_bytes);
+        /// A little-endian `u64`, with an alignment of `1`.
+        U64Le(u64, 8, to_le_bytes, from_le_bytes);
+    }
+
+    /// The byte-order wrappers of an integer type, as named by the `be` and `le` annotations of [define_layout!].
+    pub trait ByteOrdered {
+        /// The big-endian wrapper of `Self`.
+        type Be;
+        /// The little-endian wrapper of `Self`.
+        type Le;
+    }
+
+    impl ByteOrdered for u16 { type Be = U16Be; type Le = U16Le; }
+    impl ByteOrdered for u32 { type Be = U32Be; type Le = U32Le; }
+    impl ByteOrdered for u64 { type Be = U64Be; type Le = U64Le; }
+
+    /// Bytes that are reserved by a protocol, and must be zero.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    pub struct Reserved<const N: usize>([u8; N]);
+
+    impl<const N: usize> Default for Reserved<N> {
+        fn default() -> Self {
+            Reserved([0; N])
+        }
+    }
+
+    impl<const N: usize> PromiseTransmutableInto for Reserved<N> {type Archetype = Self;}
+    impl<const N: usize> PromiseTransmutableFrom for Reserved<N> {type Archetype = Self;}
+
+    unsafe impl<const N: usize> CheckBitValidity for Reserved<N> {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            bytes.iter().all(|&b| b == 0)
+        }
+    }
+
+    /// Byte views of the message types declared with [define_layout!].
+    pub trait Layout: CheckBitValidity + Sized {
+        /// View the bytes of `self`.
+        #[inline(always)]
+        fn as_bytes(&self) -> &[u8]
+        where
+            for<'a> &'a Bytes<Self>: TransmuteFrom<&'a Self>,
+        {
+            self.as_byte_array()
+        }
+
+        /// View the leading bytes of `bytes` as a `Self`, checking their alignment and validity at runtime. See [Validated::from_bytes].
+        #[inline]
+        fn try_ref_from<'a>(bytes: &'a [u8]) -> Result<&'a Self, ValidityError>
+        where
+            &'a [Self]: CastFrom<&'a [u8], (NeglectAlignment, NeglectValidity)>,
+        {
+            Validated::from_bytes(bytes).map(|val| &**val)
+        }
+    }
+
+    /// Declare a `#[repr(C)]` binary protocol message.
+    ///
+    /// This is a concise front-end for the common case of [c_struct!][crate::mem::c_struct]-like message declarations. Each field is either:
+    /// - `name: be T` or `name: le T`, an integer `T` stored in the given byte order (see [ByteOrdered]);
+    /// - `reserved name: [u8; N]`, private bytes that must be zero (see [Reserved]);
+    /// - `padding name: [u8; N]`, private bytes whose value is ignored;
+    /// - `name: T`, any other type implementing [CheckBitValidity].
+    ///
+    /// For instance:
+    /// ```rust
+    /// define_layout! {
+    ///     /// A DNS message header.
+    ///     pub struct DnsHeader {
+    ///         pub id: be u16,
+    ///         pub flags: be u16,
+    ///         pub counts: [U16Be; 4],
+    ///     }
+    /// }
+    ///
+    /// define_layout! {
+    ///     pub struct Record {
+    ///         pub kind: RecordKind,
+    ///         reserved _reserved: [u8; 3],
+    ///         pub len: le u32,
+    ///     }
+    /// }
+    ///
+    /// let record = Record::try_ref_from(bytes)?;
+    /// ```
+    /// The declared struct promises that its layout is stable, implements [CheckBitValidity] field by field, and implements [Layout]. Compilation fails if the struct has implicit padding, which must instead be declared explicitly.
+    pub macro define_layout {
+        ($(#[$attr:meta])* $vis:vis struct $name:ident { $($fields:tt)* }) => {
+            $crate::net::define_layout!(@fields [$(#[$attr])*] $vis $name [] $($fields)*);
+        },
+        (@fields $attrs:tt $vis:vis $name:ident [$($acc:tt)*] $(#[$fattr:meta])* reserved $field:ident : [u8; $n:expr] $(, $($rest:tt)*)?) => {
+            $crate::net::define_layout!(@fields $attrs $vis $name [$($acc)* ($(#[$fattr])*) () $field : ($crate::net::Reserved<{ $n }>)] $($($rest)*)?);
+        },
+        (@fields $attrs:tt $vis:vis $name:ident [$($acc:tt)*] $(#[$fattr:meta])* padding $field:ident : [u8; $n:expr] $(, $($rest:tt)*)?) => {
+            $crate::net::define_layout!(@fields $attrs $vis $name [$($acc)* ($(#[$fattr])*) () $field : ([u8; $n])] $($($rest)*)?);
+        },
+        (@fields $attrs:tt $vis:vis $name:ident [$($acc:tt)*] $(#[$fattr:meta])* $fvis:vis $field:ident : be $int:ty $(, $($rest:tt)*)?) => {
+            $crate::net::define_layout!(@fields $attrs $vis $name [$($acc)* ($(#[$fattr])*) ($fvis) $field : (<$int as $crate::net::ByteOrdered>::Be)] $($($rest)*)?);
+        },
+        (@fields $attrs:tt $vis:vis $name:ident [$($acc:tt)*] $(#[$fattr:meta])* $fvis:vis $field:ident : le $int:ty $(, $($rest:tt)*)?) => {
+            $crate::net::define_layout!(@fields $attrs $vis $name [$($acc)* ($(#[$fattr])*) ($fvis) $field : (<$int as $crate::net::ByteOrdered>::Le)] $($($rest)*)?);
+        },
+        (@fields $attrs:tt $vis:vis $name:ident [$($acc:tt)*] $(#[$fattr:meta])* $fvis:vis $field:ident : $ty:ty $(, $($rest:tt)*)?) => {
+            $crate::net::define_layout!(@fields $attrs $vis $name [$($acc)* ($(#[$fattr])*) ($fvis) $field : ($ty)] $($($rest)*)?);
+        },
+        (@fields [$(#[$attr:meta])*] $vis:vis $name:ident [$(($(#[$fattr:meta])*) ($fvis:vis) $field:ident : ($ty:ty))*]) => {
+            $(#[$attr])*
+            #[repr(C)]
+            $vis struct $name { $($(#[$fattr])* $fvis $field: $ty),* }
+
+            impl $crate::transmute::stability::PromiseTransmutableInto for $name { type Archetype = Self; }
+            impl $crate::transmute::stability::PromiseTransmutableFrom for $name { type Archetype = Self; }
+
+            // Offsets are computed by summing the sizes of the preceding
+            // fields, which is only correct if there is no implicit padding.
+            const _: [(); 0] = [(); core::mem::size_of::<$name>() - (0 $(+ core::mem::size_of::<$ty>())*)];
+
+            unsafe impl $crate::validity::CheckBitValidity for $name {
+                fn is_bit_valid(bytes: &[u8]) -> bool {
+                    <Self as $crate::validity::CheckBitValidity>::locate_invalid(bytes).is_none()
+                }
+
+                fn locate_invalid(bytes: &[u8]) -> Option<$crate::validity::InvalidField> {
+                    let mut offset = 0;
+                    $(
+                        let size = core::mem::size_of::<$ty>();
+                        if let Some(inner) = <$ty as $crate::validity::CheckBitValidity>::locate_invalid(&bytes[offset..offset + size]) {
+                            return Some($crate::validity::InvalidField { offset: offset + inner.offset, path: inner.path.within(stringify!($field)), expected: inner.expected });
+                        }
+                        offset += size;
+                    )*
+                    let _ = offset;
+                    None
+                }
+            }
+
+            impl $crate::net::Layout for $name {}
+        },
+    }
+
+    /// An Ethernet II frame header.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(C)]
+    pub struct EthernetHeader {
+        /// The destination MAC address.
+        pub dst: [u8; 6],
+        /// The source MAC address.
+        pub src: [u8; 6],
+        /// The protocol of the payload; `0x0800` for IPv4.
+        pub ether_type: U16Be,
+    }
+
+    /// An IPv4 header, without options.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(C)]
+    pub struct Ipv4Header {
+        /// The version (high nibble) and header length in words (low nibble).
+        pub version_ihl: u8,
+        /// The differentiated services code point and explicit congestion notification.
+        pub dscp_ecn: u8,
+        /// The length of the packet, including this header.
+        pub total_len: U16Be,
+        /// The identification of the packet's fragments.
+        pub id: U16Be,
+        /// The fragmentation flags and offset.
+        pub flags_fragment: U16Be,
+        /// The time to live.
+        pub ttl: u8,
+        /// The protocol of the payload; see [IpProtocol].
+        pub protocol: u8,
+        /// The header checksum.
+        pub checksum: U16Be,
+        /// The source address.
+        pub src: [u8; 4],
+        /// The destination address.
+        pub dst: [u8; 4],
+    }
+
+    /// A UDP header.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(C)]
+    pub struct UdpHeader {
+        /// The source port.
+        pub src_port: U16Be,
+        /// The destination port.
+        pub dst_port: U16Be,
+        /// The length of the datagram, including this header.
+        pub len: U16Be,
+        /// The datagram checksum.
+        pub checksum: U16Be,
+    }
+
+    /// The IP protocols understood by this demonstration.
+    ///
+    /// Not every `u8` is a valid `IpProtocol`, so the protocol field of an [Ipv4Header] is checked at runtime with [try_transmute].
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u8)]
+    pub enum IpProtocol {
+        /// The Internet Control Message Protocol.
+        Icmp = 1,
+        /// The Transmission Control Protocol.
+        Tcp = 6,
+        /// The User Datagram Protocol.
+        Udp = 17,
+    }
+
+    impl PromiseTransmutableInto for EthernetHeader {type Archetype = Self;}
+    impl PromiseTransmutableFrom for EthernetHeader {type Archetype = Self;}
+    impl PromiseTransmutableInto for     Ipv4Header {type Archetype = Self;}
+    impl PromiseTransmutableFrom for     Ipv4Header {type Archetype = Self;}
+    impl PromiseTransmutableInto for      UdpHeader {type Archetype = Self;}
+    impl PromiseTransmutableFrom for      UdpHeader {type Archetype = Self;}
+
+    impl PromiseTransmutableInto for     IpProtocol {type Archetype = Self;}
+    impl PromiseTransmutableFrom for     IpProtocol {type Archetype = Self;}
+
+    unsafe impl CheckBitValidity for IpProtocol {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            matches!(bytes[0], 1 | 6 | 17)
+        }
+    }
+
+    /// The headers and payload of a UDP datagram, borrowed from the frame that contains it.
+    #[derive(Debug, Clone, Copy)]
+    pub struct UdpPacket<'a> {
+        /// The Ethernet header of the frame.
+        pub eth: &'a EthernetHeader,
+        /// The IPv4 header of the packet.
+        pub ip: &'a Ipv4Header,
+        /// The UDP header of the datagram.
+        pub udp: &'a UdpHeader,
+        /// The payload of the datagram.
+        pub payload: &'a [u8],
+    }
+
+    /// The reasons a frame may fail to parse as a UDP datagram.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ParseError {
+        /// The frame ended before a header or payload did.
+        Truncated,
+        /// The frame does not carry an IPv4 packet.
+        NotIpv4,
+        /// The packet's protocol is not an [IpProtocol].
+        UnknownProtocol,
+        /// The packet does not carry a UDP datagram.
+        NotUdp,
+    }
+
+    /// Split a header of type `H` off the front of `bytes`, without copying.
+    fn header<'a, H>(bytes: &'a [u8]) -> Result<(&'a H, &'a [u8]), ParseError>
+    where
+        &'a [H]: CastFrom<&'a [u8]>,
+    {
+        if bytes.len() < size_of::<H>() {
+            return Err(ParseError::Truncated);
+        }
+        let (head, rest) = bytes.split_at(size_of::<H>());
+        let head: &[H] = CastFrom::cast_from(head);
+        Ok((&head[0], rest))
+    }
+
+    /// Parse an Ethernet frame carrying a UDP datagram over IPv4, without copying.
+    pub fn parse_udp<'a>(frame: &'a [u8]) -> Result<UdpPacket<'a>, ParseError>
+    where
+        &'a [EthernetHeader]: CastFrom<&'a [u8]>,
+        &'a [Ipv4Header]: CastFrom<&'a [u8]>,
+        &'a [UdpHeader]: CastFrom<&'a [u8]>,
+        IpProtocol: TransmuteFrom<u8, NeglectValidity>,
+        for<'b> &'b [u8]: CastFrom<&'b [u8]>,
+    {
+        let (eth, rest) = header::<EthernetHeader>(frame)?;
+        if eth.ether_type.get() != 0x0800 {
+            return Err(ParseError::NotIpv4);
+        }
+
+        let (ip, _) = header::<Ipv4Header>(rest)?;
+        if ip.version_ihl >> 4 != 4 {
+            return Err(ParseError::NotIpv4);
+        }
+        let protocol: IpProtocol = try_transmute(ip.protocol).map_err(|_| ParseError::UnknownProtocol)?;
+        if protocol != IpProtocol::Udp {
+            return Err(ParseError::NotUdp);
+        }
+        let ihl = usize::from(ip.version_ihl & 0x0f) * 4;
+        if ihl < size_of::<Ipv4Header>() {
+            return Err(ParseError::NotIpv4);
+        }
+        let rest = rest.get(ihl..).ok_or(ParseError::Truncated)?;
+
+        let (udp, rest) = header::<UdpHeader>(rest)?;
+        let len = usize::from(udp.len.get()).checked_sub(size_of::<UdpHeader>()).ok_or(ParseError::Truncated)?;
+        let payload = rest.get(..len).ok_or(ParseError::Truncated)?;
+
+        Ok(UdpPacket { eth, ip, udp, payload })
+    }
+}
+
+/// (Extension) Conversions between arrays of structs and structs of arrays.
+///
+/// An array of plain-old-data structs may be viewed, column by column, as a struct of arrays without copying; e.g.:
+/// ```rust
+/// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+/// #[repr(C)]
+/// pub struct Particle { pub pos: [f32; 3], pub mass: f32 }
+///
+/// let masses = column(&particles, |p| &p.mass);
+/// let total: f32 = masses.iter().sum();
+///
+/// // Or, to gather the column into its own allocation:
+/// let masses: Vec<f32> = masses.to_vec();
+/// ```
+/// Structs declared with [soa!] additionally take the offsets of their columns from their declarations, and convert to and from owned structs of `Vec`s.
+#[unstable(feature = "transmute_soa", issue = "none")]
+pub mod soa {
+    use crate::mem::{AlignLtEq, FromAnyBytes, NoUninit};
+    use core::{marker::PhantomData, mem::{align_of, size_of}, ops::Index};
+
+    /// A column of a slice of structs: a strided view of one field of each element.
+    #[derive(Debug)]
+    pub struct Column<'a, F> {
+        base: *const u8,
+        stride: usize,
+        len: usize,
+        field: PhantomData<&'a [F]>,
+    }
+
+    impl<F> Clone for Column<'_, F> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<F> Copy for Column<'_, F> {}
+
+    /// View the field selected by `field` of each element of `aos` as a [Column].
+    ///
+    /// The offset of the field is derived from the first element. Since every byte of an `S` is initialized, and every initialized bit pattern is a valid `F`, the bytes at that offset of *every* element are a valid `F`, regardless of which bytes `field` selects. Since `F` is no more aligned than `S`, the size of `S` is a multiple of the alignment of `F`, so the field of every element is as aligned as the reference `field` produces for the first.
+    ///
+    /// # Panics
+    /// Panics if `field` produces a reference outside of the element it is given.
+    pub fn column<'a, S, F>(aos: &'a [S], field: impl for<'x> Fn(&'x S) -> &'x F) -> Column<'a, F>
+    where
+        S: NoUninit,
+        F: FromAnyBytes + AlignLtEq<S>,
+    {
+        let base = match aos.first() {
+            Some(first) => {
+                let start = first as *const S as usize;
+                let offset = (field(first) as *const F as usize).wrapping_sub(start);
+                assert!(offset.checked_add(size_of::<F>()).map_or(false, |end| end <= size_of::<S>()), "field is not within the element");
+                (first as *const S).cast::<u8>().wrapping_add(offset)
+            }
+            None => aos.as_ptr().cast::<u8>(),
+        };
+        Column { base, stride: size_of::<S>(), len: aos.len(), field: PhantomData }
+    }
+
+    impl<'a, F> Column<'a, F> {
+        /// View the `F` at byte offset `offset` of each element of `aos` as a [Column].
+        ///
+        /// As with [column], every such `F` is valid and aligned, provided that it lies within the element, and that `offset` is a multiple of the alignment of `F`.
+        ///
+        /// # Panics
+        /// Panics if the `F` at `offset` is not within the element, or is misaligned.
+        pub fn at_offset<S>(aos: &'a [S], offset: usize) -> Self
+        where
+            S: NoUninit,
+            F: FromAnyBytes + AlignLtEq<S>,
+        {
+            assert!(offset.checked_add(size_of::<F>()).map_or(false, |end| end <= size_of::<S>()), "field is not within the element");
+            assert!(offset % align_of::<F>() == 0, "field is misaligned");
+            Column { base: aos.as_ptr().cast::<u8>().wrapping_add(offset), stride: size_of::<S>(), len: aos.len(), field: PhantomData }
+        }
+
+        /// The number of elements in the column.
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// `true` if the column has no elements.
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// The `i`th element of the column, or `None` if `i` is out of bounds.
+        pub fn get(&self, i: usize) -> Option<&'a F> {
+            if i < self.len {
+                // Safe because `column` verified that the field lies within
+                // each element, and that its bytes are a valid `F`. The field
+                // of the first element is aligned for `F`, since `field`
+                // produced a reference to it, and the stride is a multiple of
+                // the alignment of `F`, since `F: AlignLtEq<S>`.
+                Some(unsafe { &*self.base.add(i * self.stride).cast::<F>() })
+            } else {
+                None
+            }
+        }
+
+        /// Iterate over the elements of the column.
+        pub fn iter(&self) -> impl Iterator<Item = &'a F> + 'a {
+            let column = *self;
+            (0..column.len).filter_map(move |i| column.get(i))
+        }
+
+        /// Gather the elements of the column into a contiguous vector.
+        pub fn to_vec(&self) -> Vec<F>
+        where
+            F: Copy,
+        {
+            self.iter().copied().collect()
+        }
+    }
+
+    impl<'a, F> Index<usize> for Column<'a, F> {
+        type Output = F;
+
+        fn index(&self, i: usize) -> &F {
+            self.get(i).expect("column index out of bounds")
+        }
+    }
+
+    /// Declare a `#[repr(C)]` struct, along with its struct-of-arrays counterparts.
+    ///
+    /// The declaration of the struct is its layout descriptor: the offset of each column is computed from it, and the stride of each column is the size of the struct. Given the names of two further structs, this declares:
+    /// - an owned struct of arrays, with a `Vec` of each field, which converts [from][From] a `Vec` of the struct, and back into one;
+    /// - a borrowed struct of arrays, with a [Column] of each field, which views a slice of the struct without copying.
+    ///
+    /// ```rust
+    /// soa! {
+    ///     #[derive(Debug, Clone, Copy)]
+    ///     pub struct Particle { pub pos: [f32; 3], pub mass: f32 }
+    ///     pub struct Particles;
+    ///     pub struct ParticleColumns;
+    /// }
+    ///
+    /// let columns = ParticleColumns::new(&particles);
+    /// let total: f32 = columns.mass.iter().sum();
+    ///
+    /// let soa = Particles::from(particles); // Array of structs to struct of arrays.
+    /// let particles: Vec<Particle> = soa.into(); // And back.
+    /// ```
+    /// Converting an owned struct of arrays into a `Vec` truncates it to the length of its shortest column. The borrowed view requires that the struct is [NoUninit] and that each field is [FromAnyBytes] and no more aligned than the struct.
+    pub macro soa(
+        $(#[$attr:meta])* $vis:vis struct $name:ident { $($fvis:vis $field:ident : $ty:ty),* $(,)? }
+        $ovis:vis struct $owned:ident;
+        $cvis:vis struct $columns:ident;
+    ) {
+        $(#[$attr])*
+        #[repr(C)]
+        $vis struct $name { $($fvis $field: $ty),* }
+
+        impl $crate::transmute::stability::PromiseTransmutableInto for $name { type Archetype = Self; }
+        impl $crate::transmute::stability::PromiseTransmutableFrom for $name { type Archetype = Self; }
+
+        $ovis struct $owned { $($fvis $field: Vec<$ty>),* }
+
+        impl From<Vec<$name>> for $owned {
+            fn from(aos: Vec<$name>) -> Self {
+                let mut soa = $owned { $($field: Vec::with_capacity(aos.len())),* };
+                for $name { $($field),* } in aos {
+                    $(soa.$field.push($field);)*
+                }
+                soa
+            }
+        }
+
+        impl From<$owned> for Vec<$name> {
+            fn from(soa: $owned) -> Self {
+                let len = [$(soa.$field.len()),*].iter().copied().min().unwrap_or(0);
+                $(let mut $field = soa.$field.into_iter();)*
+                (0..len).map(|_| $name { $($field: $field.next().unwrap()),* }).collect()
+            }
+        }
+
+        #[derive(Clone, Copy)]
+        $cvis struct $columns<'a> { $($fvis $field: $crate::soa::Column<'a, $ty>),* }
+
+        impl<'a> $columns<'a> {
+            $cvis fn new(aos: &'a [$name]) -> Self {
+                let probe = core::mem::MaybeUninit::<$name>::uninit();
+                let base = probe.as_ptr();
+                $columns { $(
+                    // Safe because `&raw` creates no reference to the
+                    // uninitialized `probe`, and the field is within it.
+                    $field: $crate::soa::Column::at_offset(aos, unsafe {
+                        (&raw const (*base).$field).cast::<u8>().offset_from(base.cast::<u8>()) as usize
+                    }),
+                )* }
+            }
+        }
+    }
+}
+
+/// (Extension) Fixed-size pages of typed records, for database-style storage.
+///
+/// A [Page] is an aligned buffer of bytes, divided into slots of records. Since records are read and written in place, pages may be laid out as, e.g., the nodes of an on-disk B-tree, and written to or read from storage without serialization:
+/// ```rust
+/// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+/// #[repr(C)]
+/// pub struct Entry { pub key: u64, pub child: u32, pub len: u32 }
+///
+/// let mut page = Page::<4096>::zeroed();
+/// page.put_record(0, Entry { key: 42, child: 7, len: 0 });
+/// let entry: &Entry = page.get_record(0).unwrap();
+/// file.write_all(page.as_bytes())?;
+/// ```
+#[unstable(feature = "transmute_page", issue = "none")]
+pub mod page {
+    use crate::mem::{AlignLtEq, FromAnyBytes, NoUninit};
+    use core::{mem::size_of, ptr};
+
+    /// An `N`-byte buffer, aligned to `8` bytes, of slotted records.
+    ///
+    /// Records of type `T` occupy slots of `size_of::<T>()` bytes; slot `i` begins at byte `i * size_of::<T>()`. Records must be no more aligned than the page.
+    #[derive(Clone)]
+    #[repr(C, align(8))]
+    pub struct Page<const N: usize> {
+        bytes: [u8; N],
+    }
+
+    impl<const N: usize> Page<N> {
+        /// A page whose bytes are all zero.
+        pub const fn zeroed() -> Self {
+            Page { bytes: [0; N] }
+        }
+
+        /// A page consisting of `bytes`.
+        pub const fn from_bytes(bytes: [u8; N]) -> Self {
+            Page { bytes }
+        }
+
+        /// The bytes of the page.
+        pub fn as_bytes(&self) -> &[u8; N] {
+            &self.bytes
+        }
+
+        /// The bytes of the page, mutably.
+        pub fn as_bytes_mut(&mut self) -> &mut [u8; N] {
+            &mut self.bytes
+        }
+
+        /// The number of slots of records of type `T` in the page.
+        ///
+        /// Every slot of a zero-sized `T` fits within the page, so there are `usize::MAX` such slots.
+        pub const fn slots<T>() -> usize {
+            match size_of::<T>() {
+                0 => usize::MAX,
+                size => N / size,
+            }
+        }
+
+        /// The byte offset of `slot`, if a record of type `T` fits entirely within the page there.
+        fn offset<T>(slot: usize) -> Option<usize> {
+            let start = slot.checked_mul(size_of::<T>())?;
+            match start.checked_add(size_of::<T>())? <= N {
+                true => Some(start),
+                false => None,
+            }
+        }
+
+        /// A reference to the record of type `T` in `slot`, or `None` if the slot is out of bounds.
+        pub fn get_record<T>(&self, slot: usize) -> Option<&T>
+        where
+            T: FromAnyBytes + AlignLtEq<u64>,
+        {
+            let offset = Self::offset::<T>(slot)?;
+            // Safe because the record lies within the page, is aligned since
+            // the page is at least as aligned as `T` and `offset` is a
+            // multiple of `size_of::<T>()`, and any bytes are a valid `T`.
+            Some(unsafe { &*self.bytes.as_ptr().add(offset).cast::<T>() })
+        }
+
+        /// A mutable reference to the record of type `T` in `slot`, or `None` if the slot is out of bounds.
+        ///
+        /// Since any `T` may be written through the reference, `T` must have no uninitialized bytes.
+        pub fn get_record_mut<T>(&mut self, slot: usize) -> Option<&mut T>
+        where
+            T: FromAnyBytes + NoUninit + AlignLtEq<u64>,
+        {
+            let offset = Self::offset::<T>(slot)?;
+            // Safe for the reasons given in `get_record`, and because every `T`
+            // written through the reference leaves the page's bytes initialized.
+            Some(unsafe { &mut *self.bytes.as_mut_ptr().add(offset).cast::<T>() })
+        }
+
+        /// Write `record` to `slot`, producing it back if the slot is out of bounds.
+        pub fn put_record<T>(&mut self, slot: usize, record: T) -> Result<(), T>
+        where
+            T: NoUninit + AlignLtEq<u64>,
+        {
+            match Self::offset::<T>(slot) {
+                // Safe because the record lies within the page, is aligned as
+                // explained in `get_record`, and has no uninitialized bytes.
+                Some(offset) => Ok(unsafe { ptr::write(self.bytes.as_mut_ptr().add(offset).cast::<T>(), record) }),
+                None => Err(record),
+            }
+        }
+    }
+}
+
+/// (Extension) Auditable reads of the fields of unions.
+///
+/// Reading a field of a union is `unsafe`, since the bytes of the union may not be a valid instance of the field's type. Rather than punning types via raw field accesses, unions with promised layouts may be read through the validity machinery:
+/// ```rust
+/// #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)]
+/// #[repr(C)]
+/// pub union Register { pub bits: u32, pub float: f32, pub flag: bool }
+///
+/// let reg = Register { bits: 2 };
+/// let bits: u32 = read_variant(&reg)?;  // Ok!
+/// let flag: bool = read_variant(&reg)?; // Err: `2` is not a valid `bool`.
+/// ```
+#[unstable(feature = "transmute_union", issue = "none")]
+pub mod union {
+    use crate::{
+        mem::NoUninit,
+        transmute::{TransmuteFrom, options::NeglectValidity},
+        validity::{CheckBitValidity, ValidityError, check},
+    };
+    use core::{mem::size_of, slice};
+
+    /// Read the leading `F` of the union `u`, if its bytes are a bit-valid `F`.
+    ///
+    /// `F` must be no larger, and no more aligned, than `U`, and both must promise the stability of their layouts. Since its bytes are inspected, every byte of `U` must be initialized.
+    pub fn read_variant<F, U>(u: &U) -> Result<F, ValidityError>
+    where
+        U: NoUninit,
+        F: Copy + CheckBitValidity,
+        for<'a> &'a F: TransmuteFrom<&'a U, NeglectValidity>,
+    {
+        // Safe because `U: NoUninit`, so every byte of `u` is initialized,
+        // and `size_of::<F>() <= size_of::<U>()`, since `&U` is transmutable
+        // into `&F`.
+        let bytes = unsafe { slice::from_raw_parts((u as *const U).cast::<u8>(), size_of::<F>()) };
+        check::<F>(bytes)?;
+        // Safe because we've dynamically verified that the leading bytes of
+        // `u` are a bit-valid instance of `F`, the static check for which we
+        // chose to neglect.
+        Ok(unsafe { *<&F as TransmuteFrom<&U, NeglectValidity>>::unsafe_transmute_from(u) })
+    }
+
+    /// Read the leading `F` of the union `u`, without checking its validity.
+    ///
+    /// # Safety
+    /// The leading `size_of::<F>()` bytes of `u` must be a bit-valid instance of `F`; e.g., because `F` is the type of the field through which `u` was most recently written.
+    pub unsafe fn read_variant_unchecked<F, U>(u: &U) -> F
+    where
+        F: Copy,
+        for<'a> &'a F: TransmuteFrom<&'a U, NeglectValidity>,
+    {
+        *<&F as TransmuteFrom<&U, NeglectValidity>>::unsafe_transmute_from(u)
+    }
+}
+
+/// (Extension) Vectored I/O buffers and their platform representations.
+///
+/// [IoSlice] and [IoSliceMut] are guaranteed to be ABI-compatible with `iovec` on Unix, and with `WSABUF` on Windows. Slices of them may therefore be cast, without copying, into the buffer arrays expected by `writev`, `readv` and `WSASend`; e.g.:
+/// ```rust
+/// let bufs = [io_slice_of(slice::from_ref(&header)), io_slice_of(&body[..])];
+/// let iov: &[IoVec] = as_iovecs(&bufs);
+/// unsafe { libc::writev(fd, iov.as_ptr().cast(), iov.len() as _) };
+/// ```
+#[unstable(feature = "transmute_io", issue = "none")]
+pub mod io {
+    use crate::{
+        cast::CastFrom,
+        transmute::stability::{PromiseTransmutableFrom, PromiseTransmutableInto},
+    };
+    use std::io::{IoSlice, IoSliceMut};
+
+    /// The Unix `struct iovec`.
+    ///
+    /// ***This type is only available on Unix.***
+    #[cfg(unix)]
+    #[derive(Debug, Clone, Copy)]
+    #[repr(C)]
+    pub struct IoVec {
+        /// The start of the buffer.
+        pub iov_base: *mut core::ffi::c_void,
+        /// The length of the buffer, in bytes.
+        pub iov_len: usize,
+    }
+
+    #[cfg(unix)]
+    impl PromiseTransmutableInto for IoVec { type Archetype = Self; }
+    #[cfg(unix)]
+    impl PromiseTransmutableFrom for IoVec { type Archetype = Self; }
+
+    /// The Windows `WSABUF`.
+    ///
+    /// ***This type is only available on Windows.***
+    #[cfg(windows)]
+    #[derive(Debug, Clone, Copy)]
+    #[repr(C)]
+    pub struct IoVec {
+        /// The length of the buffer, in bytes.
+        pub len: u32,
+        /// The start of the buffer.
+        pub buf: *mut u8,
+    }
+
+    #[cfg(windows)]
+    impl PromiseTransmutableInto for IoVec { type Archetype = Self; }
+    #[cfg(windows)]
+    impl PromiseTransmutableFrom for IoVec { type Archetype = Self; }
+
+    /// `IoSlice` is documented as ABI-compatible with the platform's [IoVec]. It may be transmuted *into*, but not *from*, an `IoVec`, since its pointer must be valid for its lifetime.
+    impl<'a> PromiseTransmutableInto for IoSlice<'a> { type Archetype = Self; }
+
+    /// See [IoSlice's implementation][PromiseTransmutableInto#impl-PromiseTransmutableInto-for-IoSlice<%27a>].
+    impl<'a> PromiseTransmutableInto for IoSliceMut<'a> { type Archetype = Self; }
+
+    /// Cast a slice of `IoSlice`s into a slice of the platform's [IoVec]s.
+    #[cfg(any(unix, windows))]
+    #[inline(always)]
+    pub fn as_iovecs<'a, 'b>(bufs: &'a [IoSlice<'b>]) -> &'a [IoVec]
+    where
+        &'a [IoVec]: CastFrom<&'a [IoSlice<'b>]>,
+    {
+        CastFrom::cast_from(bufs)
+    }
+
+    /// Cast a slice of `IoSliceMut`s into a slice of the platform's [IoVec]s.
+    #[cfg(any(unix, windows))]
+    #[inline(always)]
+    pub fn as_iovecs_mut<'a, 'b>(bufs: &'a [IoSliceMut<'b>]) -> &'a [IoVec]
+    where
+        &'a [IoVec]: CastFrom<&'a [IoSliceMut<'b>]>,
+    {
+        CastFrom::cast_from(bufs)
+    }
+
+    /// An `IoSlice` over the bytes of `vals`.
+    #[inline(always)]
+    pub fn io_slice_of<'a, T>(vals: &'a [T]) -> IoSlice<'a>
+    where
+        &'a [u8]: CastFrom<&'a [T]>,
+    {
+        IoSlice::new(CastFrom::cast_from(vals))
+    }
+
+    /// An `IoSliceMut` over the bytes of `vals`.
+    ///
+    /// Since bytes may be read into the buffer, every byte sequence must be a valid `T`.
+    #[inline(always)]
+    pub fn io_slice_mut_of<'a, T>(vals: &'a mut [T]) -> IoSliceMut<'a>
+    where
+        &'a mut [u8]: CastFrom<&'a mut [T]>,
+    {
+        IoSliceMut::new(CastFrom::cast_from(vals))
+    }
+}
+
+/// (Extension) Decomposition of wide integers into arrays of narrower limbs.
+///
+/// An integer is laid out exactly as an array of narrower unsigned integers; e.g., a `u128` as a `[u64; 2]`. However, the *order* of those limbs depends on the target's endianness, which is a frequent source of bugs in cryptographic and big-number code. The conversions of [Limbs] name the order they produce:
+/// ```rust
+/// let x: u128 = 0x0123_4567_89ab_cdef_0011_2233_4455_6677;
+/// let [lo, hi]: [u64; 2] = x.to_le_limbs();
+/// assert_eq!(lo, 0x0011_2233_4455_6677);
+/// assert_eq!(hi, 0x0123_4567_89ab_cdef);
+/// assert_eq!(u128::from_le_limbs([lo, hi]), x);
+/// ```
+#[unstable(feature = "transmute_limbs", issue = "none")]
+pub mod limbs {
+    use crate::transmute::{TransmuteInto, safe_transmute};
+
+    /// Integers which may be decomposed into `N` limbs of type `L`.
+    ///
+    /// This is implemented for every `Self` that is safely transmutable into, and from, `[L; N]`; e.g., `u128` and `[u64; 2]`, `[u32; 4]`, `[u16; 8]` or `[u8; 16]`.
+    pub trait Limbs<L, const N: usize>: Sized {
+        /// Decompose `self` into limbs in the target's native order; i.e., by transmutation.
+        fn to_ne_limbs(self) -> [L; N];
+
+        /// Compose `Self` from limbs in the target's native order; i.e., by transmutation.
+        fn from_ne_limbs(limbs: [L; N]) -> Self;
+
+        /// Decompose `self` into limbs, least significant first.
+        #[inline]
+        fn to_le_limbs(self) -> [L; N] {
+            let mut limbs = self.to_ne_limbs();
+            if cfg!(target_endian = "big") { limbs.reverse(); }
+            limbs
+        }
+
+        /// Compose `Self` from limbs, least significant first.
+        #[inline]
+        fn from_le_limbs(mut limbs: [L; N]) -> Self {
+            if cfg!(target_endian = "big") { limbs.reverse(); }
+            Self::from_ne_limbs(limbs)
+        }
+
+        /// Decompose `self` into limbs, most significant first.
+        #[inline]
+        fn to_be_limbs(self) -> [L; N] {
+            let mut limbs = self.to_ne_limbs();
+            if cfg!(target_endian = "little") { limbs.reverse(); }
+            limbs
+        }
+
+        /// Compose `Self` from limbs, most significant first.
+        #[inline]
+        fn from_be_limbs(mut limbs: [L; N]) -> Self {
+            if cfg!(target_endian = "little") { limbs.reverse(); }
+            Self::from_ne_limbs(limbs)
+        }
+    }
+
+    impl<T, L, const N: usize> Limbs<L, N> for T
+    where
+        T: TransmuteInto<[L; N]>,
+        [L; N]: TransmuteInto<T>,
+    {
+        #[inline(always)]
+        fn to_ne_limbs(self) -> [L; N] { safe_transmute::<_, _, ()>(self) }
+        #[inline(always)]
+        fn from_ne_limbs(limbs: [L; N]) -> Self { safe_transmute::<_, _, ()>(limbs) }
+    }
+}
+
+/// (Extension) Byte buffers with a guaranteed alignment.
+///
+/// A `Vec<u8>` is only guaranteed to be aligned to `1`, so casting its contents into, e.g., a `&[u64]` may fail at runtime, depending on where the allocator happened to place it. The buffers of this module are instead allocated with a const-generic alignment, `ALIGN`, and so may be cast *statically* into slices of any type no more aligned than `ALIGN`:
+/// ```rust
+/// let mut buf = AlignedVec::<8>::new();
+/// std::io::copy(&mut file, &mut buf)?;
+/// let words: &[u64] = buf.cast_slice(); // Never fails.
+/// ```
+#[unstable(feature = "transmute_aligned", issue = "none")]
+pub mod aligned {
+    use crate::{
+        cast::CastFrom,
+        mem::AlignLtEq,
+        transmute::options::NeglectAlignment,
+    };
+    use core::{ops::{Deref, DerefMut}, ptr::{self, NonNull}, slice};
+    use std::alloc::{self, Layout};
+
+    /// The alignment `N`.
+    pub struct Align<const N: usize>;
+
+    /// Alignments for which a buffer may be allocated.
+    ///
+    /// This is implemented for every power of two from `1` to `4096`.
+    pub trait Alignment {
+        /// A zero-sized type with this alignment.
+        type Marker;
+    }
+
+    macro_rules! alignments {
+        ($($n:literal => $marker:ident),* $(,)?) => {$(
+            /// A zero-sized type with the alignment of its name.
+            #[repr(align($n))]
+            pub struct $marker;
+
+            impl Alignment for Align<$n> { type Marker = $marker; }
+        )*}
+    }
+
+    alignments! {
+        1 => Align1, 2 => Align2, 4 => Align4, 8 => Align8, 16 => Align16, 32 => Align32, 64 => Align64,
+        128 => Align128, 256 => Align256, 512 => Align512, 1024 => Align1024, 2048 => Align2048, 4096 => Align4096,
+    }
+
+    /// A growable byte buffer, aligned to `ALIGN`.
+    pub struct AlignedVec<const ALIGN: usize>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        ptr: NonNull<u8>,
+        len: usize,
+        cap: usize,
+    }
+
+    // Safe because `AlignedVec` uniquely owns its bytes, like a `Vec<u8>`.
+    unsafe impl<const ALIGN: usize> Send for AlignedVec<ALIGN> where Align<ALIGN>: Alignment {}
+    unsafe impl<const ALIGN: usize> Sync for AlignedVec<ALIGN> where Align<ALIGN>: Alignment {}
+
+    impl<const ALIGN: usize> AlignedVec<ALIGN>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        /// An empty buffer, which does not allocate.
+        pub const fn new() -> Self {
+            // A dangling pointer to the alignment's marker is non-null and aligned to `ALIGN`.
+            AlignedVec { ptr: NonNull::<<Align<ALIGN> as Alignment>::Marker>::dangling().cast(), len: 0, cap: 0 }
+        }
+
+        /// An empty buffer with room for at least `cap` bytes.
+        pub fn with_capacity(cap: usize) -> Self {
+            let mut vec = Self::new();
+            vec.reserve(cap);
+            vec
+        }
+
+        /// The number of bytes in the buffer.
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Produces `true` if the buffer contains no bytes.
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// The number of bytes the buffer may hold without reallocating.
+        pub fn capacity(&self) -> usize {
+            self.cap
+        }
+
+        /// Reserve room for at least `additional` more bytes. The buffer remains aligned to `ALIGN`.
+        pub fn reserve(&mut self, additional: usize) {
+            let needed = self.len.checked_add(additional).expect("capacity overflow");
+            if needed <= self.cap {
+                return;
+            }
+            let cap = needed.max(self.cap.saturating_mul(2)).max(ALIGN);
+            let layout = Layout::from_size_align(cap, ALIGN).expect("capacity overflow");
+            // Safe because `layout` is non-zero-sized, and, if the buffer has
+            // been allocated, it was allocated with `ALIGN` and `self.cap`.
+            let ptr = unsafe {
+                match self.cap {
+                    0 => alloc::alloc(layout),
+                    old => alloc::realloc(self.ptr.as_ptr(), Layout::from_size_align_unchecked(old, ALIGN), cap),
+                }
+            };
+            self.ptr = NonNull::new(ptr).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+            self.cap = cap;
+        }
+
+        /// Append a byte to the buffer.
+        pub fn push(&mut self, byte: u8) {
+            self.extend_from_slice(&[byte]);
+        }
+
+        /// Append bytes to the buffer.
+        pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+            self.reserve(bytes.len());
+            // Safe because we've reserved room for `bytes` after the
+            // initialized bytes of the buffer.
+            unsafe {
+                ptr::copy_nonoverlapping(bytes.as_ptr(), self.ptr.as_ptr().add(self.len), bytes.len());
+            }
+            self.len += bytes.len();
+        }
+
+        /// Shorten the buffer to `len` bytes. Has no effect if the buffer is already shorter.
+        pub fn truncate(&mut self, len: usize) {
+            self.len = self.len.min(len);
+        }
+
+        /// Freeze the buffer.
+        pub fn into_aligned_bytes(self) -> AlignedBytes<ALIGN> {
+            AlignedBytes { vec: self }
+        }
+
+        /// Cast the contents of the buffer into a slice of `T`. Trailing bytes that do not fill a whole `T` are excluded.
+        pub fn cast_slice<'a, T>(&'a self) -> &'a [T]
+        where
+            T: AlignLtEq<<Align<ALIGN> as Alignment>::Marker>,
+            &'a [T]: CastFrom<&'a [u8], NeglectAlignment>,
+        {
+            // Safe because the buffer is aligned to `ALIGN`, which is no less
+            // than the alignment of `T`.
+            unsafe { CastFrom::<_, NeglectAlignment>::unsafe_cast_from(&self[..]) }
+        }
+
+        /// Cast the contents of the buffer into a mutable slice of `T`. Trailing bytes that do not fill a whole `T` are excluded.
+        pub fn cast_slice_mut<'a, T>(&'a mut self) -> &'a mut [T]
+        where
+            T: AlignLtEq<<Align<ALIGN> as Alignment>::Marker>,
+            &'a mut [T]: CastFrom<&'a mut [u8], NeglectAlignment>,
+        {
+            // Safe because the buffer is aligned to `ALIGN`, which is no less
+            // than the alignment of `T`.
+            unsafe { CastFrom::<_, NeglectAlignment>::unsafe_cast_from(&mut self[..]) }
+        }
+    }
+
+    impl<const ALIGN: usize> Deref for AlignedVec<ALIGN>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        type Target = [u8];
+
+        fn deref(&self) -> &[u8] {
+            // Safe because the first `len` bytes of the buffer are initialized.
+            unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    impl<const ALIGN: usize> DerefMut for AlignedVec<ALIGN>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        fn deref_mut(&mut self) -> &mut [u8] {
+            // Safe because the first `len` bytes of the buffer are initialized.
+            unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+        }
+    }
+
+    impl<const ALIGN: usize> Drop for AlignedVec<ALIGN>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        fn drop(&mut self) {
+            if self.cap != 0 {
+                // Safe because the buffer was allocated with `ALIGN` and `self.cap`.
+                unsafe { alloc::dealloc(self.ptr.as_ptr(), Layout::from_size_align_unchecked(self.cap, ALIGN)) }
+            }
+        }
+    }
+
+    impl<const ALIGN: usize> std::io::Write for AlignedVec<ALIGN>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+            self.extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// A fixed-size byte buffer, aligned to `ALIGN`.
+    pub struct AlignedBytes<const ALIGN: usize>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        vec: AlignedVec<ALIGN>,
+    }
+
+    impl<const ALIGN: usize> AlignedBytes<ALIGN>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        /// A buffer of `len` zero bytes.
+        pub fn zeroed(len: usize) -> Self {
+            let mut vec = AlignedVec::with_capacity(len);
+            // Safe because we've reserved room for `len` bytes.
+            unsafe { ptr::write_bytes(vec.ptr.as_ptr(), 0, len) };
+            vec.len = len;
+            vec.into_aligned_bytes()
+        }
+
+        /// A buffer containing a copy of `bytes`.
+        pub fn copy_from_slice(bytes: &[u8]) -> Self {
+            let mut vec = AlignedVec::with_capacity(bytes.len());
+            vec.extend_from_slice(bytes);
+            vec.into_aligned_bytes()
+        }
+
+        /// See [AlignedVec::cast_slice].
+        pub fn cast_slice<'a, T>(&'a self) -> &'a [T]
+        where
+            T: AlignLtEq<<Align<ALIGN> as Alignment>::Marker>,
+            &'a [T]: CastFrom<&'a [u8], NeglectAlignment>,
+        {
+            self.vec.cast_slice()
+        }
+
+        /// See [AlignedVec::cast_slice_mut].
+        pub fn cast_slice_mut<'a, T>(&'a mut self) -> &'a mut [T]
+        where
+            T: AlignLtEq<<Align<ALIGN> as Alignment>::Marker>,
+            &'a mut [T]: CastFrom<&'a mut [u8], NeglectAlignment>,
+        {
+            self.vec.cast_slice_mut()
+        }
+    }
+
+    impl<const ALIGN: usize> Deref for AlignedBytes<ALIGN>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        type Target = [u8];
+
+        fn deref(&self) -> &[u8] {
+            &self.vec
+        }
+    }
+
+    impl<const ALIGN: usize> DerefMut for AlignedBytes<ALIGN>
+    where
+        Align<ALIGN>: Alignment,
+    {
+        fn deref_mut(&mut self) -> &mut [u8] {
+            &mut self.vec
+        }
+    }
+}
+
+/// (Extension) Parsing of type-length-value encoded buffers.
+///
+/// TLV is the dominant encoding of, e.g., network protocol options and file chunks: each record is a tag, a length, and that many bytes of value. [TlvIter] splits a buffer into its records, without copying, and the accessors of this module cast each value to a promised type; e.g.:
+/// ```rust
+/// let mut options = TlvIter::<u8, u8>::new(dhcp_options);
+/// for (tag, value) in &mut options {
+///     match tag {
+///         LEASE_TIME => lease = Some(value_read::<U32Be>(value)?.get()),
+///         ROUTER => routers = value_slice::<[u8; 4]>(value)?,
+///         _ => {}
+///     }
+/// }
+/// if !options.remainder().is_empty() {
+///     return Err(Error::Truncated);
+/// }
+/// ```
+#[unstable(feature = "transmute_tlv", issue = "none")]
+pub mod tlv {
+    use crate::{
+        bytes::read_unaligned_from,
+        cast::CastFrom,
+        mem::{FromAnyBytes, runtime::is_aligned_to},
+        net::{U16Be, U16Le, U32Be, U32Le},
+        transmute::options::NeglectAlignment,
+    };
+    use core::{fmt, marker::PhantomData, mem::size_of};
+
+    /// The length fields of TLV records.
+    pub trait TlvLength: FromAnyBytes + Copy {
+        /// The number of value bytes following the length field.
+        fn value_len(self) -> usize;
+    }
+
+    impl TlvLength for     u8 { fn value_len(self) -> usize { usize::from(self) } }
+    impl TlvLength for    u16 { fn value_len(self) -> usize { usize::from(self) } }
+    impl TlvLength for    u32 { fn value_len(self) -> usize { self as usize } }
+    impl TlvLength for  U16Be { fn value_len(self) -> usize { usize::from(self.get()) } }
+    impl TlvLength for  U16Le { fn value_len(self) -> usize { usize::from(self.get()) } }
+    impl TlvLength for  U32Be { fn value_len(self) -> usize { self.get() as usize } }
+    impl TlvLength for  U32Le { fn value_len(self) -> usize { self.get() as usize } }
+
+    /// An iterator over the `(tag, value)` records of a TLV-encoded buffer.
+    ///
+    /// Each record is a `Tag`, followed by a `Len` counting the bytes of the value, followed by the value. Neither field need be aligned. Iteration stops at the first record that does not fit in the buffer; the unparsed bytes are then available from [remainder][TlvIter::remainder].
+    #[derive(Debug, Clone)]
+    pub struct TlvIter<'a, Tag, Len = u8> {
+        bytes: &'a [u8],
+        _fields: PhantomData<fn() -> (Tag, Len)>,
+    }
+
+    impl<'a, Tag, Len> TlvIter<'a, Tag, Len> {
+        /// Iterate over the records of `bytes`.
+        pub fn new(bytes: &'a [u8]) -> Self {
+            TlvIter { bytes, _fields: PhantomData }
+        }
+
+        /// The bytes that have not yet been parsed into records.
+        ///
+        /// Once iteration has stopped, these are empty if, and only if, the buffer consisted entirely of well-formed records.
+        pub fn remainder(&self) -> &'a [u8] {
+            self.bytes
+        }
+    }
+
+    impl<'a, Tag, Len> Iterator for TlvIter<'a, Tag, Len>
+    where
+        Tag: FromAnyBytes,
+        Len: TlvLength,
+    {
+        type Item = (Tag, &'a [u8]);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let tag: Tag = read_unaligned_from(self.bytes).ok()?;
+            let len: Len = read_unaligned_from(&self.bytes[size_of::<Tag>()..]).ok()?;
+            let start = size_of::<Tag>() + size_of::<Len>();
+            let end = start.checked_add(len.value_len())?;
+            let value = self.bytes.get(start..end)?;
+            self.bytes = &self.bytes[end..];
+            Some((tag, value))
+        }
+    }
+
+    /// The error produced when a TLV value cannot be cast to the requested type.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ValueError {
+        /// The value has the wrong number of bytes.
+        Length {
+            /// The number of bytes the requested type requires; for slices, the size of one element.
+            expected: usize,
+            /// The number of bytes of the value.
+            found: usize,
+        },
+        /// The value is not suitably aligned for the requested type.
+        Misaligned,
+    }
+
+    impl fmt::Display for ValueError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ValueError::Length { expected, found } =>
+                    write!(f, "TLV value of {} bytes does not match the expected size of {}", found, expected),
+                ValueError::Misaligned =>
+                    f.write_str("TLV value is not suitably aligned for the requested type"),
+            }
+        }
+    }
+
+    impl std::error::Error for ValueError {}
+
+    /// Read a copy of a value of exactly `size_of::<T>()` bytes, which need not be aligned.
+    #[inline]
+    pub fn value_read<T: FromAnyBytes>(value: &[u8]) -> Result<T, ValueError> {
+        match value.len() == size_of::<T>() {
+            true => read_unaligned_from(value).ok(),
+            false => None,
+        }.ok_or(ValueError::Length { expected: size_of::<T>(), found: value.len() })
+    }
+
+    /// View a value of exactly `size_of::<T>()` bytes as a `T`, checking its alignment at runtime.
+    #[inline]
+    pub fn value_ref<'a, T>(value: &'a [u8]) -> Result<&'a T, ValueError>
+    where
+        &'a [T]: CastFrom<&'a [u8], NeglectAlignment>,
+    {
+        match value_slice::<T>(value)? {
+            [val] => Ok(val),
+            _ => Err(ValueError::Length { expected: size_of::<T>(), found: value.len() }),
+        }
+    }
+
+    /// View a value as a slice of `T`, checking its length and alignment at runtime.
+    ///
+    /// The length of the value must be a multiple of `size_of::<T>()`.
+    #[inline]
+    pub fn value_slice<'a, T>(value: &'a [u8]) -> Result<&'a [T], ValueError>
+    where
+        &'a [T]: CastFrom<&'a [u8], NeglectAlignment>,
+    {
+        if size_of::<T>() == 0 || value.len() % size_of::<T>() != 0 {
+            return Err(ValueError::Length { expected: size_of::<T>(), found: value.len() });
+        }
+        if !is_aligned_to::<T>(value.as_ptr()) {
+            return Err(ValueError::Misaligned);
+        }
+        // Safe because we've dynamically verified the alignment requirement,
+        // whose static check we chose to neglect.
+        Ok(unsafe { CastFrom::<_, NeglectAlignment>::unsafe_cast_from(value) })
+    }
+}
+
+/// (Extension) Demonstration: zero-copy parsing of ELF file headers.
+///
+/// This module exercises the whole stack on a real file format: stability declarations, little-endian wrappers, validated enum fields, [Validated] byte views and slice casts. Every type has an alignment of `1`, so headers may be viewed in place regardless of where the file was loaded; e.g.:
+/// ```rust
+/// let file = std::fs::read("/bin/true")?;
+/// let elf = parse_elf64(&file)?;
+/// println!("entry point: {:#x}", elf.header.entry.get());
+/// for segment in elf.segments {
+///     println!("{:#x}: {} bytes", segment.vaddr.get(), segment.memsz.get());
+/// }
+/// ```
+/// Only 64-bit, little-endian files are understood.
+#[unstable(feature = "transmute_demo", issue = "none")]
+pub mod elf {
+    use crate::{
+        cast::CastFrom,
+        net::{U16Le, U32Le, U64Le},
+        transmute::{TransmuteFrom, options::{NeglectAlignment, NeglectValidity}, stability::{PromiseTransmutableFrom, PromiseTransmutableInto}},
+        validity::{CheckBitValidity, FieldPath, InvalidField, Validated, ValidityError, try_transmute},
+    };
+    use core::{convert::TryFrom, fmt, mem::size_of};
+
+    /// The magic number that begins every ELF file: `\x7fELF`.
+    ///
+    /// Any other four bytes are an invalid `Magic`.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    pub struct Magic([u8; 4]);
+
+    /// The word size of an ELF file.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u8)]
+    pub enum Class {
+        /// 32-bit.
+        Elf32 = 1,
+        /// 64-bit.
+        Elf64 = 2,
+    }
+
+    /// The byte order of an ELF file.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u8)]
+    pub enum Data {
+        /// Little-endian.
+        Lsb = 1,
+        /// Big-endian.
+        Msb = 2,
+    }
+
+    /// The kind of an ELF file, as stored in the `kind` field of its [Elf64Header].
+    ///
+    /// Not every `u16` is a valid `ObjectType`, so the field is checked at runtime with [try_transmute]; see [Elf64Header::object_type].
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u16)]
+    pub enum ObjectType {
+        /// An unknown type.
+        None = 0,
+        /// A relocatable object.
+        Relocatable = 1,
+        /// An executable.
+        Executable = 2,
+        /// A shared object, or position-independent executable.
+        Shared = 3,
+        /// A core dump.
+        Core = 4,
+    }
+
+    /// The identification bytes at the start of an ELF file.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(C)]
+    pub struct Ident {
+        /// The magic number.
+        pub magic: Magic,
+        /// The word size of the file.
+        pub class: Class,
+        /// The byte order of the file.
+        pub data: Data,
+        /// The version of the identification; `1`.
+        pub version: u8,
+        /// The operating system ABI.
+        pub os_abi: u8,
+        /// The version of the operating system ABI.
+        pub abi_version: u8,
+        /// Unused.
+        pub pad: [u8; 7],
+    }
+
+    /// The header of a 64-bit, little-endian ELF file.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(C)]
+    pub struct Elf64Header {
+        /// The identification bytes.
+        pub ident: Ident,
+        /// The kind of the file; see [ObjectType].
+        pub kind: U16Le,
+        /// The target architecture.
+        pub machine: U16Le,
+        /// The version of the file; `1`.
+        pub version: U32Le,
+        /// The virtual address of the entry point.
+        pub entry: U64Le,
+        /// The file offset of the program header table.
+        pub phoff: U64Le,
+        /// The file offset of the section header table.
+        pub shoff: U64Le,
+        /// Architecture-specific flags.
+        pub flags: U32Le,
+        /// The size of this header.
+        pub ehsize: U16Le,
+        /// The size of each program header.
+        pub phentsize: U16Le,
+        /// The number of program headers.
+        pub phnum: U16Le,
+        /// The size of each section header.
+        pub shentsize: U16Le,
+        /// The number of section headers.
+        pub shnum: U16Le,
+        /// The index of the section header of the section name table.
+        pub shstrndx: U16Le,
+    }
+
+    /// A program header of a 64-bit, little-endian ELF file, describing a segment.
+    /* #[derive(PromiseTransmutableFrom, PromiseTransmutableInto)] */
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(C)]
+    pub struct Elf64ProgramHeader {
+        /// The kind of the segment; e.g., `1` for a loadable segment.
+        pub kind: U32Le,
+        /// The permissions of the segment.
+        pub flags: U32Le,
+        /// The file offset of the segment.
+        pub offset: U64Le,
+        /// The virtual address of the segment.
+        pub vaddr: U64Le,
+        /// The physical address of the segment.
+        pub paddr: U64Le,
+        /// The size of the segment in the file.
+        pub filesz: U64Le,
+        /// The size of the segment in memory.
+        pub memsz: U64Le,
+        /// The alignment of the segment.
+        pub align: U64Le,
+    }
+
+    impl PromiseTransmutableInto for              Magic {type Archetype = Self;}
+    impl PromiseTransmutableFrom for              Magic {type Archetype = Self;}
+    impl PromiseTransmutableInto for              Class {type Archetype = Self;}
+    impl PromiseTransmutableFrom for              Class {type Archetype = Self;}
+    impl PromiseTransmutableInto for               Data {type Archetype = Self;}
+    impl PromiseTransmutableFrom for               Data {type Archetype = Self;}
+    impl PromiseTransmutableInto for         ObjectType {type Archetype = Self;}
+    impl PromiseTransmutableFrom for         ObjectType {type Archetype = Self;}
+    impl PromiseTransmutableInto for              Ident {type Archetype = Self;}
+    impl PromiseTransmutableFrom for              Ident {type Archetype = Self;}
+    impl PromiseTransmutableInto for        Elf64Header {type Archetype = Self;}
+    impl PromiseTransmutableFrom for        Elf64Header {type Archetype = Self;}
+    impl PromiseTransmutableInto for Elf64ProgramHeader {type Archetype = Self;}
+    impl PromiseTransmutableFrom for Elf64ProgramHeader {type Archetype = Self;}
+
+    unsafe impl CheckBitValidity for Magic {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            bytes == b"\x7fELF"
+        }
+    }
+
+    unsafe impl CheckBitValidity for Class {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            matches!(bytes[0], 1 | 2)
+        }
+    }
+
+    unsafe impl CheckBitValidity for Data {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            matches!(bytes[0], 1 | 2)
+        }
+    }
+
+    unsafe impl CheckBitValidity for ObjectType {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            matches!(u16::from_ne_bytes([bytes[0], bytes[1]]), 0..=4)
+        }
+    }
+
+    unsafe impl CheckBitValidity for Ident {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            Magic::is_bit_valid(&bytes[0..4]) && Class::is_bit_valid(&bytes[4..5]) && Data::is_bit_valid(&bytes[5..6])
+        }
+
+        fn locate_invalid(bytes: &[u8]) -> Option<InvalidField> {
+            let field = |offset, name, expected| Some(InvalidField { offset, path: FieldPath::field(name), expected });
+            if !Magic::is_bit_valid(&bytes[0..4]) {
+                field(0, "magic", "Magic")
+            } else if !Class::is_bit_valid(&bytes[4..5]) {
+                field(4, "class", "Class")
+            } else if !Data::is_bit_valid(&bytes[5..6]) {
+                field(5, "data", "Data")
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Only the identification bytes of the header have invalid bit patterns.
+    unsafe impl CheckBitValidity for Elf64Header {
+        fn is_bit_valid(bytes: &[u8]) -> bool {
+            Ident::is_bit_valid(&bytes[..size_of::<Ident>()])
+        }
+
+        fn locate_invalid(bytes: &[u8]) -> Option<InvalidField> {
+            Ident::locate_invalid(&bytes[..size_of::<Ident>()]).map(|field| InvalidField { path: field.path.within("ident"), ..field })
+        }
+    }
+
+    impl Elf64Header {
+        /// The kind of the file, if it is a known [ObjectType].
+        pub fn object_type(&self) -> Result<ObjectType, ValidityError>
+        where
+            ObjectType: TransmuteFrom<u16, NeglectValidity>,
+            for<'a> &'a [u8]: CastFrom<&'a [u16]>,
+        {
+            try_transmute(self.kind.get())
+        }
+    }
+
+    /// The header and segments of an ELF file, borrowed from the file's bytes.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Elf64<'a> {
+        /// The file header.
+        pub header: &'a Elf64Header,
+        /// The program headers.
+        pub segments: &'a [Elf64ProgramHeader],
+    }
+
+    /// The reasons a file may fail to parse as a 64-bit, little-endian ELF file.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ParseError {
+        /// The file ended before a header did.
+        Truncated,
+        /// The header is not bit-valid; e.g., its magic number is wrong.
+        Invalid(ValidityError),
+        /// The file is not 64-bit.
+        NotElf64,
+        /// The file is not little-endian.
+        NotLittleEndian,
+        /// The file's program headers are not [Elf64ProgramHeader]s.
+        BadProgramHeaders,
+    }
+
+    impl fmt::Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ParseError::Truncated => f.write_str("file is truncated"),
+                ParseError::Invalid(err) => write!(f, "invalid ELF header: {}", err),
+                ParseError::NotElf64 => f.write_str("file is not a 64-bit ELF file"),
+                ParseError::NotLittleEndian => f.write_str("file is not a little-endian ELF file"),
+                ParseError::BadProgramHeaders => f.write_str("file's program headers are malformed"),
+            }
+        }
+    }
+
+    impl std::error::Error for ParseError {}
+
+    /// Parse the header and program headers of a 64-bit, little-endian ELF file, without copying.
+    pub fn parse_elf64<'a>(file: &'a [u8]) -> Result<Elf64<'a>, ParseError>
+    where
+        &'a [Elf64Header]: CastFrom<&'a [u8], (NeglectAlignment, NeglectValidity)>,
+        &'a [Elf64ProgramHeader]: CastFrom<&'a [u8]>,
+    {
+        if file.len() < size_of::<Elf64Header>() {
+            return Err(ParseError::Truncated);
+        }
+        let header: &Elf64Header = Validated::from_bytes(file).map(|header| &**header).map_err(ParseError::Invalid)?;
+        if header.ident.class != Class::Elf64 {
+            return Err(ParseError::NotElf64);
+        }
+        if header.ident.data != Data::Lsb {
+            return Err(ParseError::NotLittleEndian);
+        }
+
+        if usize::from(header.phentsize.get()) != size_of::<Elf64ProgramHeader>() {
+            return Err(ParseError::BadProgramHeaders);
+        }
+        let start = usize::try_from(header.phoff.get()).map_err(|_| ParseError::Truncated)?;
+        let len = usize::from(header.phnum.get()).checked_mul(size_of::<Elf64ProgramHeader>()).ok_or(ParseError::Truncated)?;
+        let table = start.checked_add(len).and_then(|end| file.get(start..end)).ok_or(ParseError::Truncated)?;
+        let segments: &[Elf64ProgramHeader] = CastFrom::cast_from(table);
+
+        Ok(Elf64 { header, segments })
+    }
+}